@@ -0,0 +1,152 @@
+//! `aoc bench`: times parse, part 1 and part 2 separately for each day and prints a table.
+
+use common::inputs::{input_dir, input_path};
+use common::{Day, Timings};
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortBy {
+    Day,
+    Parse,
+    Part1,
+    Part2,
+    Total,
+}
+impl SortBy {
+    fn parse(s: &str) -> Result<SortBy, String> {
+        match s {
+            "day" => Ok(SortBy::Day),
+            "parse" => Ok(SortBy::Parse),
+            "part1" => Ok(SortBy::Part1),
+            "part2" => Ok(SortBy::Part2),
+            "total" => Ok(SortBy::Total),
+            _ => Err(format!(
+                "Invalid sort column: {} (expected day, parse, part1, part2 or total)",
+                s
+            )),
+        }
+    }
+}
+
+pub struct BenchArgs {
+    days: Vec<u32>,
+    input_dir: PathBuf,
+    runs: u32,
+    sort: SortBy,
+}
+
+impl BenchArgs {
+    pub fn parse(args: &[String]) -> Result<BenchArgs, String> {
+        let mut days = Vec::new();
+        let mut input_dir = input_dir("inputs");
+        let mut runs = 1;
+        let mut sort = SortBy::Day;
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if arg == "--input-dir" {
+                input_dir = args.next().ok_or("--input-dir requires a path")?.into();
+            } else if arg == "--runs" {
+                let n = args.next().ok_or("--runs requires a count")?;
+                runs = match n.parse::<u32>() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Invalid run count: {}", n)),
+                };
+            } else if arg == "--sort" {
+                sort = SortBy::parse(args.next().ok_or("--sort requires a column")?)?;
+            } else {
+                days.push(
+                    arg.parse::<u32>()
+                        .map_err(|_| format!("Invalid day: {}", arg))?,
+                );
+            }
+        }
+        Ok(BenchArgs {
+            days,
+            input_dir,
+            runs,
+            sort,
+        })
+    }
+}
+
+/// The fastest time seen for each stage, over all runs. Each stage is minimised independently,
+/// since noise only ever makes a run slower.
+fn best_of(runs: &[Timings]) -> Timings {
+    let best = |stage: fn(&Timings) -> Duration| runs.iter().map(stage).min().unwrap_or_default();
+    Timings {
+        parse: best(|t| t.parse),
+        part1: best(|t| t.part1),
+        part2: best(|t| t.part2),
+    }
+}
+
+fn ms(d: Duration) -> String {
+    format!("{:.3} ms", d.as_secs_f64() * 1000.0)
+}
+
+/// Benchmarks every requested day (all of them if none were given) that has an input file in
+/// the input directory. Days without one are skipped with a note on stderr.
+pub fn bench(solutions: Vec<Box<dyn Day>>, args: &BenchArgs) -> Result<(), String> {
+    let mut results = Vec::new();
+    for solution in solutions {
+        let day = solution.day();
+        if !args.days.is_empty() && !args.days.contains(&day) {
+            continue;
+        }
+        let path = input_path(&args.input_dir, day);
+        let input = match std::fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(_) => {
+                eprintln!("Skipping day {}: no input at {}", day, path.display());
+                continue;
+            }
+        };
+        let mut runs = Vec::new();
+        for _ in 0..args.runs {
+            let (_, timings) = solution
+                .run_timed(&input)
+                .map_err(|e| e.pretty(&path.display().to_string()))?;
+            runs.push(timings);
+        }
+        results.push((day, best_of(&runs)));
+    }
+    if results.is_empty() {
+        return Err(format!("No inputs found in {}", args.input_dir.display()));
+    }
+
+    // Slowest first for the timing columns, since those are the ones worth looking at.
+    match args.sort {
+        SortBy::Day => results.sort_by_key(|(day, _)| *day),
+        SortBy::Parse => results.sort_by_key(|(_, t)| std::cmp::Reverse(t.parse)),
+        SortBy::Part1 => results.sort_by_key(|(_, t)| std::cmp::Reverse(t.part1)),
+        SortBy::Part2 => results.sort_by_key(|(_, t)| std::cmp::Reverse(t.part2)),
+        SortBy::Total => results.sort_by_key(|(_, t)| std::cmp::Reverse(t.total())),
+    }
+
+    println!(
+        "{:>3}  {:>14}  {:>14}  {:>14}  {:>14}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+    let mut total = Duration::ZERO;
+    for (day, t) in &results {
+        println!(
+            "{:>3}  {:>14}  {:>14}  {:>14}  {:>14}",
+            day,
+            ms(t.parse),
+            ms(t.part1),
+            ms(t.part2),
+            ms(t.total())
+        );
+        total += t.total();
+    }
+    println!(
+        "{:>3}  {:>14}  {:>14}  {:>14}  {:>14}",
+        "All",
+        "",
+        "",
+        "",
+        ms(total)
+    );
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use common::inputs::{example_paths, input_dir, input_path};
+use common::{Day, Format};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+mod bench;
+
+const USAGE: &str = "Usage:
+    aoc [-v|-vv] run <DAY> [--input <PATH> | --examples] [--format <text|json>]
+    aoc bench [DAY...] [--input-dir <DIR>] [--runs <N>] [--sort <COLUMN>]
+    aoc list
+
+`run` solves DAY (1-25) for the puzzle input at PATH, or on stdin if PATH is -. Without
+--input it reads dayNN.txt from the input directory: $AOC_INPUT_DIR if set, otherwise inputs.
+--examples runs every examples/dayNN-*.txt instead, with any constants the puzzle changes for
+its examples. With --format json it prints a JSON object
+with day, part1, part2 and elapsed_ms for each input.
+
+`bench` times parsing and each part separately for the given days (default: all), reading
+inputs from DIR/dayNN.txt (default DIR: the input directory, as for run). With --runs, each
+stage reports its best time over N runs. COLUMN is one of day (the default), parse, part1, part2 or total; timing columns
+sort slowest first.
+
+Solvers log to stderr: -v shows debug output and -vv trace output. RUST_LOG filters per day,
+e.g. RUST_LOG=aoc09=trace.";
+
+fn solutions() -> Vec<Box<dyn Day>> {
+    vec![
+        Box::new(aoc01::Day01),
+        Box::new(aoc02::Day02),
+        Box::new(aoc03::Day03),
+        Box::new(aoc04::Day04),
+        Box::new(aoc05::Day05),
+        Box::new(aoc06::Day06),
+        Box::new(aoc07::Day07),
+        Box::new(aoc08::Day08),
+        Box::new(aoc09::Day09),
+        Box::new(aoc10::Day10),
+        Box::new(aoc11::Day11),
+        Box::new(aoc12::Day12),
+        Box::new(aoc13::Day13),
+        Box::new(aoc14::Day14),
+        Box::new(aoc15::Day15),
+        Box::new(aoc16::Day16),
+        Box::new(aoc17::Day17),
+        Box::new(aoc18::Day18),
+        Box::new(aoc19::Day19),
+        Box::new(aoc20::Day20),
+        Box::new(aoc21::Day21),
+        Box::new(aoc22::Day22),
+        Box::new(aoc23::Day23),
+        Box::new(aoc24::Day24),
+        Box::new(aoc25::Day25),
+    ]
+}
+
+fn find_solution(day: u32) -> Option<Box<dyn Day>> {
+    solutions().into_iter().find(|s| s.day() == day)
+}
+
+/// Where `run` reads its input from.
+enum Input {
+    /// `dayNN.txt` in the input directory.
+    Default,
+    Stdin,
+    Path(PathBuf),
+    /// Every `dayNN-*.txt` in `examples/`.
+    Examples,
+}
+
+struct RunArgs {
+    day: u32,
+    input: Input,
+    format: Format,
+}
+
+impl RunArgs {
+    fn parse(args: &[String]) -> Result<RunArgs, String> {
+        let mut day = None;
+        let mut input = Input::Default;
+        let mut format = Format::Text;
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if arg == "--input" || arg == "-i" || arg == "--examples" {
+                if !matches!(input, Input::Default) {
+                    return Err("Only one of --input and --examples can be given".to_string());
+                }
+                input = match arg.as_str() {
+                    "--examples" => Input::Examples,
+                    _ => match args.next().ok_or("--input requires a path")?.as_str() {
+                        "-" => Input::Stdin,
+                        path => Input::Path(path.into()),
+                    },
+                };
+            } else if arg == "--format" {
+                format = Format::parse(args.next().ok_or("--format requires a format")?)?;
+            } else if day.is_none() {
+                day = Some(
+                    arg.parse::<u32>()
+                        .map_err(|_| format!("Invalid day: {}", arg))?,
+                );
+            } else {
+                return Err(format!("Unexpected argument: {}", arg));
+            }
+        }
+        Ok(RunArgs {
+            day: day.ok_or("Missing day")?,
+            input,
+            format,
+        })
+    }
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("Unable to read {}: {}", path.display(), e))
+}
+
+fn run(args: &RunArgs) -> Result<(), String> {
+    let solution = find_solution(args.day).ok_or(format!("No solution for day {}", args.day))?;
+    // Each input along with the name to report errors against and, if there are several, the
+    // name to head its answers with.
+    let inputs = match &args.input {
+        Input::Default => {
+            let path = input_path(&input_dir("inputs"), args.day);
+            let input = read(&path).map_err(|e| {
+                format!(
+                    "{}\n(use --input <PATH> to pick a file, or --input - for stdin)",
+                    e
+                )
+            })?;
+            vec![(input, path.display().to_string(), None)]
+        }
+        Input::Stdin => vec![(common::read_stdin(), "<stdin>".to_string(), None)],
+        Input::Path(path) => vec![(read(path)?, path.display().to_string(), None)],
+        Input::Examples => {
+            let paths = example_paths(Path::new("examples"), args.day)
+                .map_err(|e| format!("Unable to read examples: {}", e))?;
+            if paths.is_empty() {
+                return Err(format!("No examples for day {} in examples", args.day));
+            }
+            let mut inputs = Vec::new();
+            for path in paths {
+                let name = path.display().to_string();
+                inputs.push((read(&path)?, name.clone(), Some(name)));
+            }
+            inputs
+        }
+    };
+    for (input, source, heading) in inputs {
+        let result = match args.input {
+            Input::Examples => solution.run_example_timed(&input),
+            _ => solution.run_timed(&input),
+        };
+        let (answers, timings) = result.map_err(|e| e.pretty(&source))?;
+        if args.format == Format::Text {
+            match heading {
+                Some(heading) => println!("Day {} ({})", solution.day(), heading),
+                None => println!("Day {}", solution.day()),
+            }
+        }
+        println!(
+            "{}",
+            common::report(solution.day(), &answers, &timings, args.format)
+        );
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    common::init_logging(common::take_verbosity(&mut args));
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("run") => RunArgs::parse(&args[1..]).and_then(|args| run(&args)),
+        Some("bench") => {
+            bench::BenchArgs::parse(&args[1..]).and_then(|args| bench::bench(solutions(), &args))
+        }
+        Some("list") => {
+            for s in solutions() {
+                println!("{}", s.day());
+            }
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! What the elves are carrying, summed per elf, with the queries the puzzle (and anyone planning
+//! snacks) might want to make of it.
+//!
+//! The input is read a line at a time and only the per-elf totals are kept, so even very large
+//! inventories take memory in proportion to the number of elves rather than the size of the input.
+//! Totals are 64-bit, and hand-edited input is tolerated: Windows line endings, whitespace around
+//! the numbers, and extra blank lines (see `BlankLines`).
+
+use common::parse::{lines, Line};
+use common::ParseError;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+/// What to make of more than one blank line in a row, or of blank lines before the first elf.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlankLines {
+    /// Any number of blank lines separates two elves, and leading ones are ignored.
+    #[default]
+    Collapse,
+    /// Every blank line ends an elf, so each extra one adds an elf carrying nothing.
+    EmptyElves,
+    /// Only a single blank line may separate elves; anything else is an error.
+    Reject,
+}
+
+/// The total calories carried by each elf. Elves are numbered from 0 in input order.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Inventory {
+    totals: Vec<u64>,
+}
+
+impl Inventory {
+    pub fn parse(input: &str) -> Result<Inventory, ParseError> {
+        Inventory::parse_with(input, BlankLines::default())
+    }
+    pub fn parse_with(input: &str, blank_lines: BlankLines) -> Result<Inventory, ParseError> {
+        let mut tally = Tally::new(blank_lines);
+        for line in lines(input) {
+            tally.add(line)?;
+        }
+        Ok(tally.finish())
+    }
+    /// Like `parse`, but reading from `reader` so the input never has to be held in memory.
+    pub fn read(reader: impl BufRead) -> Result<Inventory, ParseError> {
+        Inventory::read_with(reader, BlankLines::default())
+    }
+    pub fn read_with(
+        reader: impl BufRead,
+        blank_lines: BlankLines,
+    ) -> Result<Inventory, ParseError> {
+        let mut tally = Tally::new(blank_lines);
+        for (i, text) in reader.lines().enumerate() {
+            let text = text.map_err(|e| ParseError::new(format!("readable input ({})", e)))?;
+            tally.add(Line {
+                number: i + 1,
+                text: &text,
+            })?;
+        }
+        Ok(tally.finish())
+    }
+
+    pub fn totals(&self) -> &[u64] {
+        &self.totals
+    }
+    pub fn len(&self) -> usize {
+        self.totals.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.totals.is_empty()
+    }
+    /// The `n` elves carrying the most.
+    pub fn top(&self, n: usize) -> TopN {
+        let mut top = TopN::new(n);
+        for (elf, &calories) in self.totals.iter().enumerate() {
+            top.push(elf, calories);
+        }
+        top
+    }
+    /// The elf carrying the most, and how much.
+    pub fn max(&self) -> Option<(usize, u64)> {
+        self.top(1).elves().first().copied()
+    }
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        let sum = self.totals.iter().map(|&c| u128::from(c)).sum::<u128>();
+        Some(sum as f64 / self.len() as f64)
+    }
+    /// The middle total, or the mean of the two middle totals if there are an even number.
+    pub fn median(&self) -> Option<f64> {
+        let mut totals = self.totals.clone();
+        let n = totals.len();
+        if n == 0 {
+            return None;
+        }
+        let (lower, &mut upper, _) = totals.select_nth_unstable(n / 2);
+        if n % 2 == 1 {
+            Some(upper as f64)
+        } else {
+            let below = *lower.iter().max().unwrap();
+            Some((below as f64 + upper as f64) / 2.0)
+        }
+    }
+    /// The smallest total that at least `p` percent of elves carry no more than (the
+    /// nearest-rank percentile). `p` is clamped to 0-100, and 0 gives the smallest total.
+    pub fn percentile(&self, p: f64) -> Option<u64> {
+        let mut totals = self.totals.clone();
+        let n = totals.len();
+        if n == 0 {
+            return None;
+        }
+        let rank = (p.clamp(0.0, 100.0) / 100.0 * n as f64).ceil() as usize;
+        Some(*totals.select_nth_unstable(rank.max(1) - 1).1)
+    }
+}
+
+impl FromIterator<u64> for Inventory {
+    fn from_iter<I: IntoIterator<Item = u64>>(totals: I) -> Inventory {
+        Inventory {
+            totals: totals.into_iter().collect(),
+        }
+    }
+}
+
+/// Adds up the current elf's calories line by line. A blank line ends an elf.
+struct Tally {
+    blank_lines: BlankLines,
+    totals: Vec<u64>,
+    /// `None` until the current elf's first item.
+    current: Option<u64>,
+}
+impl Tally {
+    fn new(blank_lines: BlankLines) -> Tally {
+        Tally {
+            blank_lines,
+            totals: Vec::new(),
+            current: None,
+        }
+    }
+    fn add(&mut self, line: Line) -> Result<(), ParseError> {
+        // Splitting on whitespace also drops the `\r` of a Windows line ending.
+        let mut tokens = line.tokens();
+        let Some(token) = tokens.next() else {
+            return self.end_elf(line);
+        };
+        let calories = token.parse::<u64>("calorie count")?;
+        tokens.end()?;
+        let total = self.current.unwrap_or(0).checked_add(calories);
+        self.current = Some(total.ok_or_else(|| token.error("a total that fits in 64 bits"))?);
+        Ok(())
+    }
+    fn end_elf(&mut self, line: Line) -> Result<(), ParseError> {
+        match (self.current.take(), self.blank_lines) {
+            (Some(total), _) => self.totals.push(total),
+            (None, BlankLines::Collapse) => {}
+            (None, BlankLines::EmptyElves) => self.totals.push(0),
+            (None, BlankLines::Reject) => {
+                return Err(
+                    line.error_at_end("calorie count (elves are separated by one blank line)")
+                )
+            }
+        }
+        Ok(())
+    }
+    fn finish(mut self) -> Inventory {
+        self.totals.extend(self.current);
+        Inventory {
+            totals: self.totals,
+        }
+    }
+}
+
+/// The `n` largest totals pushed so far, along with which elf carried each. Only `n` entries are
+/// ever kept, in a min-heap so the smallest is the one to drop when a larger total comes along.
+/// On ties the earlier elf wins.
+#[derive(Debug, Clone)]
+pub struct TopN {
+    n: usize,
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+impl TopN {
+    pub fn new(n: usize) -> TopN {
+        TopN {
+            n,
+            heap: BinaryHeap::with_capacity(n + 1),
+        }
+    }
+    pub fn push(&mut self, elf: usize, calories: u64) {
+        self.heap.push(Reverse((calories, Reverse(elf))));
+        if self.heap.len() > self.n {
+            self.heap.pop();
+        }
+    }
+    /// The total of the kept elves' calories, widened so that it can't overflow however many
+    /// are kept.
+    pub fn sum(&self) -> u128 {
+        self.heap
+            .iter()
+            .map(|&Reverse((calories, _))| u128::from(calories))
+            .sum()
+    }
+    /// The elves and their totals, largest first.
+    pub fn elves(&self) -> Vec<(usize, u64)> {
+        let mut elves = self
+            .heap
+            .iter()
+            .map(|&Reverse((calories, Reverse(elf)))| (elf, calories))
+            .collect::<Vec<_>>();
+        elves.sort_by_key(|&(elf, calories)| (Reverse(calories), elf));
+        elves
+    }
+}
@@ -0,0 +1,38 @@
+use common::{Answer, ParseError, Solution};
+use log::debug;
+
+pub mod inventory;
+
+pub use inventory::Inventory;
+
+pub type Input = Inventory;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    Inventory::parse(input)
+}
+
+pub fn solve_part1(inventory: &Input) -> Answer {
+    inventory.top(1).sum().into()
+}
+
+pub fn solve_part2(inventory: &Input) -> Answer {
+    let top = inventory.top(3);
+    debug!("top three (elf, calories): {:?}", top.elves());
+    top.sum().into()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
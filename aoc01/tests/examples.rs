@@ -0,0 +1,20 @@
+use aoc01::{solve_part1, solve_part2, Day01};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day01-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day01>(EXAMPLE)), Answer::from(24000));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day01>(EXAMPLE)), Answer::from(45000));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day01>();
+}
@@ -0,0 +1,114 @@
+use aoc01::inventory::{BlankLines, TopN};
+use aoc01::{solve_part1, solve_part2, Inventory};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day01-example.txt");
+
+#[test]
+fn top_n_reports_elves_largest_first() {
+    let inventory = Inventory::parse(EXAMPLE).unwrap();
+    assert_eq!(inventory.totals(), [6000, 4000, 11000, 24000, 10000]);
+    assert_eq!(inventory.max(), Some((3, 24000)));
+    let top = inventory.top(3);
+    assert_eq!(top.elves(), [(3, 24000), (2, 11000), (4, 10000)]);
+    assert_eq!(top.sum(), 45000);
+    assert_eq!(inventory.top(10).elves().len(), 5);
+    assert_eq!(inventory.top(0).sum(), 0);
+}
+
+#[test]
+fn ties_go_to_the_earlier_elf() {
+    let mut top = TopN::new(2);
+    for (elf, calories) in [5, 7, 5, 7, 5].into_iter().enumerate() {
+        top.push(elf, calories);
+    }
+    assert_eq!(top.elves(), [(1, 7), (3, 7)]);
+}
+
+#[test]
+fn statistics() {
+    let inventory = Inventory::parse(EXAMPLE).unwrap();
+    assert_eq!(inventory.mean(), Some(11000.0));
+    assert_eq!(inventory.median(), Some(10000.0));
+    assert_eq!(inventory.percentile(0.0), Some(4000));
+    assert_eq!(inventory.percentile(50.0), Some(10000));
+    assert_eq!(inventory.percentile(90.0), Some(24000));
+    assert_eq!(inventory.percentile(100.0), Some(24000));
+
+    let even = Inventory::from_iter([1, 4, 2, 3]);
+    assert_eq!(even.median(), Some(2.5));
+    assert_eq!(even.percentile(25.0), Some(1));
+
+    let empty = Inventory::default();
+    assert_eq!(
+        (empty.max(), empty.mean(), empty.median()),
+        (None, None, None)
+    );
+}
+
+#[test]
+fn read_streams_the_same_result_as_parse() {
+    let streamed = Inventory::read(EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(streamed, Inventory::parse(EXAMPLE).unwrap());
+}
+
+#[test]
+fn tolerates_crlf_and_surrounding_whitespace() {
+    let inventory = Inventory::parse("1000\r\n 2000 \r\n\r\n3000\t\r\n").unwrap();
+    assert_eq!(inventory.totals(), [3000, 3000]);
+}
+
+#[test]
+fn totals_past_32_bits() {
+    let inventory = Inventory::parse("4000000000\n4000000000\n\n1\n").unwrap();
+    assert_eq!(inventory.totals(), [8_000_000_000, 1]);
+    let e = Inventory::parse("18446744073709551615\n1\n").unwrap_err();
+    assert_eq!(e.location.unwrap().line, 2);
+}
+
+#[test]
+fn totals_past_63_bits() {
+    let inventory = Inventory::parse("9223372036854775808\n\n1\n").unwrap();
+    assert_eq!(
+        solve_part1(&inventory),
+        Answer::Text("9223372036854775808".to_string())
+    );
+    assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+    assert_eq!(Answer::from(i64::MAX as u64), Answer::Number(i64::MAX));
+}
+
+#[test]
+fn top_sums_past_64_bits() {
+    let inventory =
+        Inventory::parse("8000000000000000000\n\n8000000000000000000\n\n8000000000000000000\n")
+            .unwrap();
+    assert_eq!(inventory.top(3).sum(), 24_000_000_000_000_000_000);
+    assert_eq!(
+        solve_part2(&inventory),
+        Answer::Text("24000000000000000000".to_string())
+    );
+}
+
+#[test]
+fn blank_line_policies() {
+    let input = "\n1\n\n\n2\n\n3\n";
+    let collapsed = Inventory::parse_with(input, BlankLines::Collapse).unwrap();
+    assert_eq!(collapsed.totals(), [1, 2, 3]);
+    let empty = Inventory::parse_with(input, BlankLines::EmptyElves).unwrap();
+    assert_eq!(empty.totals(), [0, 1, 0, 2, 3]);
+    let e = Inventory::parse_with(input, BlankLines::Reject).unwrap_err();
+    assert_eq!(e.location.unwrap().line, 1);
+    let e = Inventory::parse_with(&input[1..], BlankLines::Reject).unwrap_err();
+    assert_eq!(e.location.unwrap().line, 3);
+}
+
+#[test]
+fn bad_lines_are_reported_with_their_number() {
+    let e = Inventory::parse("100\n200\n\n3o0\n").unwrap_err();
+    let location = e.location.unwrap();
+    assert_eq!((location.line, location.column), (4, 1));
+    assert_eq!(e.expected, "calorie count");
+    let e = Inventory::read("100\n200 300\n".as_bytes()).unwrap_err();
+    assert_eq!(e.location.unwrap().line, 2);
+    assert_eq!(e.expected, "end of line");
+}
@@ -0,0 +1,144 @@
+//! Evaluating a strategy guide under every way of reading its second column. Part 1 reads X, Y
+//! and Z as rock, paper and scissors, and part 2 as lose, draw and win, but any assignment of
+//! distinct plays or outcomes to the three letters is a possible decoding, including mixed ones
+//! like "X means rock, Y means draw, Z means win".
+
+use crate::game::{Game, Move, Outcome};
+use crate::Input;
+use std::fmt;
+
+/// What a letter in the second column tells us to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Meaning {
+    Play(Move),
+    Outcome(Outcome),
+}
+
+/// What each of X, Y and Z means.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decoding(pub [Meaning; 3]);
+
+impl Decoding {
+    /// The decoding part 1 uses.
+    pub fn plays() -> Decoding {
+        Decoding([0, 1, 2].map(|m| Meaning::Play(Move(m))))
+    }
+    /// The decoding part 2 uses.
+    pub fn outcomes() -> Decoding {
+        Decoding(Outcome::ALL.map(Meaning::Outcome))
+    }
+    /// Every decoding where the three letters mean different things.
+    pub fn all(game: &Game) -> Vec<Decoding> {
+        let meanings = game
+            .moves()
+            .map(Meaning::Play)
+            .chain(Outcome::ALL.map(Meaning::Outcome))
+            .collect::<Vec<_>>();
+        let mut decodings = Vec::new();
+        for &x in &meanings {
+            for &y in &meanings {
+                for &z in &meanings {
+                    if x != y && y != z && x != z {
+                        decodings.push(Decoding([x, y, z]));
+                    }
+                }
+            }
+        }
+        decodings
+    }
+    /// The total score for following the guide this way. `None` if the game has no move giving
+    /// one of the outcomes asked for.
+    pub fn score(&self, input: &Input) -> Option<i32> {
+        let mut score = 0;
+        for round in &input.rounds {
+            let mine = match self.0[round.second] {
+                Meaning::Play(mine) => mine,
+                Meaning::Outcome(outcome) => input.game.choose(round.their_play, outcome)?,
+            };
+            score += input.game.score(mine, round.their_play);
+        }
+        Some(score)
+    }
+    /// E.g. `X=Rock Y=Draw Z=Win`.
+    pub fn describe(&self, game: &Game) -> String {
+        let mut s = String::new();
+        for (letter, meaning) in ['X', 'Y', 'Z'].into_iter().zip(self.0) {
+            if !s.is_empty() {
+                s.push(' ');
+            }
+            let meaning = match meaning {
+                Meaning::Play(m) => game.name(m).to_string(),
+                Meaning::Outcome(o) => format!("{:?}", o),
+            };
+            s += &format!("{}={}", letter, meaning);
+        }
+        s
+    }
+}
+
+/// Every decoding of a guide, ranked by the score it gives.
+pub struct Analysis {
+    /// Highest score first. Ties keep the order of `Decoding::all`.
+    pub ranked: Vec<(Decoding, i32)>,
+}
+
+impl Analysis {
+    pub fn new(input: &Input) -> Analysis {
+        let mut ranked = Decoding::all(&input.game)
+            .into_iter()
+            .filter_map(|d| Some((d, d.score(input)?)))
+            .collect::<Vec<_>>();
+        ranked.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+        Analysis { ranked }
+    }
+    pub fn best(&self) -> Option<(Decoding, i32)> {
+        self.ranked.first().copied()
+    }
+    pub fn worst(&self) -> Option<(Decoding, i32)> {
+        self.ranked.last().copied()
+    }
+    /// Where `decoding` places, counting from 1 for the best. Tied decodings share a rank.
+    pub fn rank(&self, decoding: Decoding) -> Option<usize> {
+        let (_, score) = self.ranked.iter().find(|(d, _)| *d == decoding)?;
+        Some(self.ranked.iter().filter(|(_, s)| s > score).count() + 1)
+    }
+}
+
+/// A report for `aoc02 --analyse`, one decoding per line.
+pub struct Report<'a>(pub &'a Input, pub &'a Analysis);
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Report(input, analysis) = self;
+        let game = &input.game;
+        writeln!(
+            f,
+            "{} decodings of {} rounds",
+            analysis.ranked.len(),
+            input.rounds.len()
+        )?;
+        let lines = [
+            ("Best", analysis.best().map(|(d, _)| d)),
+            ("Worst", analysis.worst().map(|(d, _)| d)),
+            ("Part 1", Some(Decoding::plays())),
+            ("Part 2", Some(Decoding::outcomes())),
+        ];
+        for (label, decoding) in lines {
+            let Some((decoding, score)) = decoding.and_then(|d| Some((d, d.score(input)?))) else {
+                continue;
+            };
+            write!(
+                f,
+                "{:<7} {:<26} {:>8}",
+                label,
+                decoding.describe(game),
+                score
+            )?;
+            if let Some(rank) = analysis.rank(decoding) {
+                write!(f, "  (rank {})", rank)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+//! Rock-paper-scissors style games, described as data: a list of moves, which moves beat which,
+//! and how a round is scored. The puzzle's game is `Game::rock_paper_scissors`, but anything from
+//! Rock-Paper-Scissors-Lizard-Spock up works the same way.
+
+/// A move, by its position in the game's list of moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Move(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    names: Vec<String>,
+    /// `beats[a][b]` is whether move `a` beats move `b`.
+    beats: Vec<Vec<bool>>,
+    /// Points for playing each move, whatever the outcome.
+    move_scores: Vec<i32>,
+    /// Points for losing, drawing and winning.
+    outcome_scores: [i32; 3],
+}
+
+impl Game {
+    /// A game where `beats` lists every (winner, loser) pair; any other pair of moves draws.
+    pub fn new(
+        names: &[&str],
+        beats: &[(usize, usize)],
+        move_scores: &[i32],
+        outcome_scores: [i32; 3],
+    ) -> Result<Game, String> {
+        let n = names.len();
+        if move_scores.len() != n {
+            return Err(format!("{} moves but {} move scores", n, move_scores.len()));
+        }
+        let mut table = vec![vec![false; n]; n];
+        for &(winner, loser) in beats {
+            if winner >= n || loser >= n {
+                return Err(format!("no move {} in a game of {}", winner.max(loser), n));
+            }
+            if winner == loser {
+                return Err(format!("{} can't beat itself", names[winner]));
+            }
+            if table[loser][winner] {
+                return Err(format!(
+                    "{} and {} can't both beat each other",
+                    names[winner], names[loser]
+                ));
+            }
+            table[winner][loser] = true;
+        }
+        Ok(Game {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            beats: table,
+            move_scores: move_scores.to_vec(),
+            outcome_scores,
+        })
+    }
+    /// The balanced tournament on an odd number of moves, where each move beats the half of the
+    /// others just before it, wrapping around. Moves score 1, 2, 3... in order, and rounds score
+    /// 0 for a loss, 3 for a draw and 6 for a win, as in the puzzle.
+    pub fn cyclic(names: &[&str]) -> Result<Game, String> {
+        let n = names.len();
+        if n.is_multiple_of(2) {
+            return Err(format!(
+                "a cyclic game needs an odd number of moves, not {}",
+                n
+            ));
+        }
+        let beats = (0..n)
+            .flat_map(|winner| (1..=n / 2).map(move |k| (winner, (winner + n - k) % n)))
+            .collect::<Vec<_>>();
+        let move_scores = (1..=n as i32).collect::<Vec<_>>();
+        Game::new(names, &beats, &move_scores, [0, 3, 6])
+    }
+    pub fn rock_paper_scissors() -> Game {
+        Game::cyclic(&["Rock", "Paper", "Scissors"]).unwrap()
+    }
+    pub fn rock_paper_scissors_lizard_spock() -> Game {
+        Game::cyclic(&["Rock", "Spock", "Paper", "Lizard", "Scissors"]).unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+    pub fn moves(&self) -> impl Iterator<Item = Move> {
+        (0..self.len()).map(Move)
+    }
+    pub fn name(&self, m: Move) -> &str {
+        &self.names[m.0]
+    }
+    pub fn find(&self, name: &str) -> Option<Move> {
+        self.names.iter().position(|n| n == name).map(Move)
+    }
+    pub fn outcome(&self, mine: Move, theirs: Move) -> Outcome {
+        if self.beats[mine.0][theirs.0] {
+            Outcome::Win
+        } else if self.beats[theirs.0][mine.0] {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+    /// What a round scores for the player who played `mine`.
+    pub fn score(&self, mine: Move, theirs: Move) -> i32 {
+        self.move_scores[mine.0] + self.outcome_scores[self.outcome(mine, theirs) as usize]
+    }
+    /// A move that gets the `desired` outcome against `theirs`, picking the highest-scoring one
+    /// if there's a choice. `None` if no move does.
+    pub fn choose(&self, theirs: Move, desired: Outcome) -> Option<Move> {
+        self.moves()
+            .filter(|&mine| self.outcome(mine, theirs) == desired)
+            .max_by_key(|&mine| (self.move_scores[mine.0], std::cmp::Reverse(mine)))
+    }
+}
@@ -0,0 +1,95 @@
+use common::parse::{lines, Token};
+use common::{Answer, ParseError, Solution};
+
+pub mod decoding;
+pub mod game;
+
+use game::{Game, Move, Outcome};
+
+/// One line of the strategy guide. The second column can be read either as a play or as a desired
+/// outcome, so we keep which of X, Y or Z it was and interpret it when scoring.
+pub struct Round {
+    their_play: Move,
+    second: usize,
+}
+impl Round {
+    fn my_play(&self) -> Move {
+        Move(self.second)
+    }
+    fn desired_outcome(&self) -> Outcome {
+        Outcome::ALL[self.second]
+    }
+}
+
+/// The strategy guide for a game of rock-paper-scissors.
+pub struct Input {
+    game: Game,
+    rounds: Vec<Round>,
+}
+
+/// The index of `token` in `letters`, e.g. 1 for `B` in `ABC`.
+fn column(token: Token, letters: &str, expected: &str) -> Result<usize, ParseError> {
+    let mut chars = token.text().chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => letters.find(c).ok_or_else(|| token.error(expected)),
+        _ => Err(token.error(expected)),
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut rounds = Vec::new();
+    for line in lines(input) {
+        let mut tokens = line.tokens();
+        let their_play = column(
+            tokens.next_token("their play")?,
+            "ABC",
+            "their play (A, B or C)",
+        )?;
+        let second = column(tokens.next_token("second column")?, "XYZ", "X, Y or Z")?;
+        tokens.end()?;
+        rounds.push(Round {
+            their_play: Move(their_play),
+            second,
+        });
+    }
+    Ok(Input {
+        game: Game::rock_paper_scissors(),
+        rounds,
+    })
+}
+
+pub fn solve_part1(input: &Input) -> Answer {
+    let mut score: i32 = 0;
+    for round in &input.rounds {
+        score += input.game.score(round.my_play(), round.their_play);
+    }
+    score.into()
+}
+
+pub fn solve_part2(input: &Input) -> Answer {
+    let mut score: i32 = 0;
+    for round in &input.rounds {
+        let my_play = input
+            .game
+            .choose(round.their_play, round.desired_outcome())
+            .unwrap();
+        score += input.game.score(my_play, round.their_play);
+    }
+    score.into()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
@@ -0,0 +1,46 @@
+use aoc02::decoding::{Analysis, Decoding, Meaning, Report};
+use aoc02::game::{Game, Move, Outcome};
+
+const EXAMPLE: &str = "A Y\nB X\nC Z\n";
+
+#[test]
+fn every_decoding_is_ranked() {
+    let input = aoc02::parse(EXAMPLE).unwrap();
+    let analysis = Analysis::new(&input);
+    // Three distinct meanings out of three plays and three outcomes.
+    assert_eq!(analysis.ranked.len(), 6 * 5 * 4);
+    assert_eq!(Decoding::plays().score(&input), Some(15));
+    assert_eq!(Decoding::outcomes().score(&input), Some(12));
+    let (_, best) = analysis.best().unwrap();
+    let (_, worst) = analysis.worst().unwrap();
+    assert_eq!(best, 24);
+    assert_eq!(worst, 6);
+    assert_eq!(analysis.rank(analysis.best().unwrap().0), Some(1));
+    assert!(analysis.rank(Decoding::plays()) < analysis.rank(Decoding::outcomes()));
+}
+
+#[test]
+fn mixed_decodings() {
+    let input = aoc02::parse(EXAMPLE).unwrap();
+    // X = rock, Y = draw, Z = win: drawing with rock (4), rock losing to paper (1), and rock beating
+    // scissors (7).
+    let mixed = Decoding([
+        Meaning::Play(Move(0)),
+        Meaning::Outcome(Outcome::Draw),
+        Meaning::Outcome(Outcome::Win),
+    ]);
+    assert_eq!(mixed.score(&input), Some(12));
+    assert_eq!(
+        mixed.describe(&Game::rock_paper_scissors()),
+        "X=Rock Y=Draw Z=Win"
+    );
+}
+
+#[test]
+fn report() {
+    let input = aoc02::parse(EXAMPLE).unwrap();
+    let report = Report(&input, &Analysis::new(&input)).to_string();
+    assert!(report.starts_with("120 decodings of 3 rounds\n"));
+    assert!(report.contains("Part 1  X=Rock Y=Paper Z=Scissors"));
+    assert!(report.contains("Part 2  X=Loss Y=Draw Z=Win"));
+}
@@ -0,0 +1,20 @@
+use aoc02::{solve_part1, solve_part2, Day02};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day02-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day02>(EXAMPLE)), Answer::from(15));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day02>(EXAMPLE)), Answer::from(12));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day02>();
+}
@@ -0,0 +1,63 @@
+use aoc02::game::{Game, Move, Outcome};
+
+#[test]
+fn classic_game() {
+    let game = Game::rock_paper_scissors();
+    let [rock, paper, scissors] = ["Rock", "Paper", "Scissors"].map(|m| game.find(m).unwrap());
+    assert_eq!(game.outcome(paper, rock), Outcome::Win);
+    assert_eq!(game.outcome(rock, paper), Outcome::Loss);
+    assert_eq!(game.outcome(scissors, scissors), Outcome::Draw);
+    assert_eq!(game.score(paper, rock), 8);
+    assert_eq!(game.score(rock, paper), 1);
+    assert_eq!(game.score(scissors, scissors), 6);
+    assert_eq!(game.choose(rock, Outcome::Win), Some(paper));
+    assert_eq!(game.choose(rock, Outcome::Loss), Some(scissors));
+}
+
+#[test]
+fn rock_paper_scissors_lizard_spock() {
+    let game = Game::rock_paper_scissors_lizard_spock();
+    let m = |name| game.find(name).unwrap();
+    for (winner, loser) in [
+        ("Scissors", "Paper"),
+        ("Paper", "Rock"),
+        ("Rock", "Lizard"),
+        ("Lizard", "Spock"),
+        ("Spock", "Scissors"),
+        ("Scissors", "Lizard"),
+        ("Lizard", "Paper"),
+        ("Paper", "Spock"),
+        ("Spock", "Rock"),
+        ("Rock", "Scissors"),
+    ] {
+        assert_eq!(game.outcome(m(winner), m(loser)), Outcome::Win);
+        assert_eq!(game.outcome(m(loser), m(winner)), Outcome::Loss);
+    }
+    // Every move has two that beat it; the higher-scoring one is chosen.
+    assert_eq!(game.choose(m("Rock"), Outcome::Win), Some(m("Paper")));
+    assert_eq!(game.choose(m("Rock"), Outcome::Draw), Some(m("Rock")));
+}
+
+#[test]
+fn custom_game() {
+    // Nothing beats the bomb, and it scores nothing.
+    let game = Game::new(&["Rock", "Bomb"], &[(1, 0)], &[1, 0], [0, 1, 2]).unwrap();
+    assert_eq!(game.score(Move(1), Move(0)), 2);
+    assert_eq!(game.choose(Move(1), Outcome::Win), None);
+    assert!(Game::new(&["A", "B"], &[(0, 1), (1, 0)], &[1, 2], [0, 3, 6]).is_err());
+    assert!(Game::new(&["A", "B"], &[(0, 2)], &[1, 2], [0, 3, 6]).is_err());
+    assert_eq!(
+        Game::new(&["A", "B"], &[(0, 0)], &[1, 2], [0, 3, 6]),
+        Err("A can't beat itself".to_string())
+    );
+}
+
+#[test]
+fn cyclic_games_need_an_odd_number_of_moves() {
+    assert_eq!(Game::cyclic(&["A", "B", "C"]).unwrap().len(), 3);
+    assert_eq!(
+        Game::cyclic(&["A", "B", "C", "D"]),
+        Err("a cyclic game needs an odd number of moves, not 4".to_string())
+    );
+    assert!(Game::cyclic(&[]).is_err());
+}
@@ -0,0 +1,131 @@
+//! Compares aoc03's bitmask `ItemSet`s with the `HashSet` sets they replaced, on a large
+//! generated input: splitting every rucksack into its two compartments, then finding what's shared
+//! within each rucksack and within each group of three. The `ItemSet` side goes through
+//! `aoc03::parse_with` and `common_items`, so it measures the code the puzzle actually runs.
+
+use aoc03::{common_items, Item, GROUP_SIZE};
+use common::testing::Rng;
+use std::collections::HashSet;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const RUCKSACKS: usize = 300_000;
+const RUNS: u32 = 5;
+
+/// Groups of rucksacks that are valid puzzle input, from a fixed seed so every run sees the same
+/// thing. Each group has a badge, and each of its rucksacks has its own pool of the other
+/// items, so the badge is the only item they all carry. Each rucksack has one item from its pool
+/// in both compartments, fills them out from either half of the rest of its pool, and carries
+/// the badge in one compartment or the other.
+fn generate() -> String {
+    const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+    let mut text = String::new();
+    for _ in 0..RUCKSACKS / GROUP_SIZE {
+        let mut items = ITEMS.to_vec();
+        for i in (1..items.len()).rev() {
+            items.swap(i, rng.below(i + 1));
+        }
+        let (badge, rest) = items.split_first().unwrap();
+        let pool_size = rest.len() / GROUP_SIZE;
+        for pool in rest.chunks_exact(pool_size) {
+            let (shared, rest) = pool.split_first().unwrap();
+            let (left, right) = rest.split_at(rest.len() / 2);
+            let len = 2 + rng.below(23);
+            let mut c1 = vec![*shared];
+            let mut c2 = vec![*shared];
+            match rng.below(2) {
+                0 => c1.push(*badge),
+                _ => c2.push(*badge),
+            }
+            while c1.len() < len {
+                c1.push(left[rng.below(left.len())]);
+            }
+            while c2.len() < len {
+                c2.push(right[rng.below(right.len())]);
+            }
+            for compartment in [&mut c1, &mut c2] {
+                for i in (1..compartment.len()).rev() {
+                    compartment.swap(i, rng.below(i + 1));
+                }
+                text.extend(compartment.iter().map(|&b| b as char));
+            }
+            text.push('\n');
+        }
+    }
+    text
+}
+
+fn priority(c: char) -> u32 {
+    Item::new(c).unwrap().priority()
+}
+
+fn with_hash_sets(text: &str) -> (u32, u32) {
+    let rucksacks = text
+        .lines()
+        .map(|line| {
+            let (c1, c2) = line.split_at(line.len() / 2);
+            (
+                c1.chars().collect::<HashSet<char>>(),
+                c2.chars().collect::<HashSet<char>>(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let shared = rucksacks
+        .iter()
+        .map(|(c1, c2)| c1.intersection(c2).map(|&c| priority(c)).sum::<u32>())
+        .sum();
+    let badges = rucksacks
+        .chunks_exact(GROUP_SIZE)
+        .map(|group| {
+            let items = group
+                .iter()
+                .map(|(c1, c2)| c1.union(c2).copied().collect::<HashSet<char>>())
+                .collect::<Vec<_>>();
+            items[0]
+                .iter()
+                .filter(|c| items[1].contains(c) && items[2].contains(c))
+                .map(|&c| priority(c))
+                .sum::<u32>()
+        })
+        .sum();
+    (shared, badges)
+}
+
+fn with_item_sets(text: &str) -> (u32, u32) {
+    let input = aoc03::parse_with(text, GROUP_SIZE).unwrap();
+    let rucksacks = input.rucksacks();
+    let shared = rucksacks.iter().map(|rs| rs.shared().priority()).sum();
+    let badges = rucksacks
+        .chunks(GROUP_SIZE)
+        .map(|group| common_items(group).priority())
+        .sum();
+    (shared, badges)
+}
+
+/// The fastest of `RUNS` runs, and what it computed.
+fn time(f: impl Fn(&str) -> (u32, u32), text: &str) -> (Duration, (u32, u32)) {
+    let mut best = Duration::MAX;
+    let mut result = (0, 0);
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        result = black_box(f(black_box(text)));
+        best = best.min(start.elapsed());
+    }
+    (best, result)
+}
+
+fn main() {
+    let text = generate();
+    let (hash_sets, expected) = time(with_hash_sets, &text);
+    let (item_sets, result) = time(with_item_sets, &text);
+    assert_eq!(result, expected, "the two implementations disagree");
+    println!("{} rucksacks, best of {} runs", RUCKSACKS, RUNS);
+    for (name, t) in [("HashSet<char>", hash_sets), ("ItemSet", item_sets)] {
+        println!("{:<14} {:>10.3} ms", name, t.as_secs_f64() * 1000.0);
+    }
+    println!(
+        "Speedup        {:>10.1}x",
+        hash_sets.as_secs_f64() / item_sets.as_secs_f64()
+    );
+}
@@ -0,0 +1,125 @@
+//! Items and sets of items. There are only 52 kinds of item, so a set is a 64-bit mask with one
+//! bit per priority, and finding what several rucksacks have in common is just `&`.
+
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+
+/// An item type, `a`-`z` or `A`-`Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Item(char);
+
+impl Item {
+    pub fn new(c: char) -> Option<Item> {
+        c.is_ascii_alphabetic().then_some(Item(c))
+    }
+    /// 1-26 for `a`-`z` and 27-52 for `A`-`Z`.
+    pub fn priority(self) -> u32 {
+        if self.0.is_ascii_lowercase() {
+            1 + self.0 as u32 - 'a' as u32
+        } else {
+            27 + self.0 as u32 - 'A' as u32
+        }
+    }
+    fn from_priority(priority: u32) -> Item {
+        let c = if priority <= 26 {
+            b'a' + (priority - 1) as u8
+        } else {
+            b'A' + (priority - 27) as u8
+        };
+        Item(c as char)
+    }
+    fn bit(self) -> u64 {
+        1 << (self.priority() - 1)
+    }
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+    pub const ALL: ItemSet = ItemSet((1 << 52) - 1);
+
+    pub fn insert(&mut self, item: Item) {
+        self.0 |= item.bit();
+    }
+    pub fn contains(self, item: Item) -> bool {
+        self.0 & item.bit() != 0
+    }
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+    /// The only item in the set, if it has exactly one.
+    pub fn single(self) -> Option<Item> {
+        match self.len() {
+            1 => Some(Item::from_priority(self.0.trailing_zeros() + 1)),
+            _ => None,
+        }
+    }
+    /// The items in the set, lowest priority first.
+    pub fn iter(self) -> impl Iterator<Item = Item> {
+        (1..=52)
+            .filter(move |&p| self.0 & (1 << (p - 1)) != 0)
+            .map(Item::from_priority)
+    }
+    /// The sum of the priorities of every item in the set.
+    pub fn priority(self) -> u32 {
+        self.iter().map(Item::priority).sum()
+    }
+}
+
+impl FromIterator<Item> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = Item>>(items: I) -> ItemSet {
+        let mut set = ItemSet::EMPTY;
+        for item in items {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+}
+impl BitAndAssign for ItemSet {
+    fn bitand_assign(&mut self, other: ItemSet) {
+        self.0 &= other.0;
+    }
+}
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+    fn bitor(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+}
+impl BitOrAssign for ItemSet {
+    fn bitor_assign(&mut self, other: ItemSet) {
+        self.0 |= other.0;
+    }
+}
+
+impl fmt::Display for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for item in self.iter() {
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+/// The items found in every one of `sets`. Everything is common to no sets at all.
+pub fn common(sets: impl IntoIterator<Item = ItemSet>) -> ItemSet {
+    sets.into_iter().fold(ItemSet::ALL, |a, b| a & b)
+}
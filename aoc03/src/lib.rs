@@ -0,0 +1,174 @@
+pub mod items;
+
+use common::parse::{lines, Line};
+use common::{Answer, ParseError, Solution};
+pub use items::{Item, ItemSet};
+
+/// How many elves carry each badge, in the puzzle.
+pub const GROUP_SIZE: usize = 3;
+
+/// A rucksack with exactly one item packed in both of its compartments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    c1: ItemSet,
+    c2: ItemSet,
+    shared: Item,
+}
+
+impl Rucksack {
+    fn parse(line: Line) -> Result<Rucksack, ParseError> {
+        let mut c1 = ItemSet::EMPTY;
+        let mut c2 = ItemSet::EMPTY;
+        let len = line.text.chars().count();
+        if !len.is_multiple_of(2) {
+            return Err(line.error_at_end("an even number of items"));
+        }
+        for (column, c) in line.chars() {
+            let item = Item::new(c).ok_or_else(|| line.error_at(column, "item (a-z or A-Z)"))?;
+            if column > len / 2 {
+                c2.insert(item);
+            } else {
+                c1.insert(item);
+            }
+        }
+        let shared = (c1 & c2).single().ok_or_else(|| {
+            line.token().error(format!(
+                "exactly one item in both compartments (they share {})",
+                describe(c1 & c2)
+            ))
+        })?;
+        Ok(Rucksack { c1, c2, shared })
+    }
+    pub fn compartments(&self) -> [ItemSet; 2] {
+        [self.c1, self.c2]
+    }
+    /// Everything in the rucksack, in either compartment.
+    pub fn items(&self) -> ItemSet {
+        self.c1 | self.c2
+    }
+    /// The item packed in both compartments.
+    pub fn shared(&self) -> Item {
+        self.shared
+    }
+}
+
+/// The rucksacks, split into groups that each have exactly one badge: one item that every elf in
+/// the group carries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    rucksacks: Vec<Rucksack>,
+    group_size: usize,
+    badges: Vec<Item>,
+}
+
+impl Input {
+    pub fn rucksacks(&self) -> &[Rucksack] {
+        &self.rucksacks
+    }
+    pub fn group_size(&self) -> usize {
+        self.group_size
+    }
+    /// Each group's badge, in input order.
+    pub fn badges(&self) -> &[Item] {
+        &self.badges
+    }
+    /// The groups of rucksacks, alongside their badges.
+    pub fn groups(&self) -> impl Iterator<Item = (&[Rucksack], Item)> {
+        self.rucksacks
+            .chunks(self.group_size)
+            .zip(self.badges.iter().copied())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    parse_with(input, GROUP_SIZE)
+}
+
+/// Like `parse`, but with groups of `group_size` rucksacks. The last group must be complete.
+pub fn parse_with(input: &str, group_size: usize) -> Result<Input, ParseError> {
+    if group_size == 0 {
+        return Err(ParseError::new("a group size of at least 1"));
+    }
+    let lines = lines(input).collect::<Vec<_>>();
+    let rucksacks = lines
+        .iter()
+        .map(|&line| Rucksack::parse(line))
+        .collect::<Result<Vec<_>, _>>()?;
+    let badges = lines
+        .chunks(group_size)
+        .zip(rucksacks.chunks(group_size))
+        .map(|(lines, group)| badge(lines, group, group_size))
+        .collect::<Result<_, _>>()?;
+    Ok(Input {
+        rucksacks,
+        group_size,
+        badges,
+    })
+}
+
+fn badge(lines: &[Line], group: &[Rucksack], group_size: usize) -> Result<Item, ParseError> {
+    let first = lines[0];
+    if group.len() < group_size {
+        let last = lines[lines.len() - 1];
+        return Err(last.error_at_end(format!(
+            "{} more rucksack(s) to finish the group of {} starting on line {}",
+            group_size - group.len(),
+            group_size,
+            first.number
+        )));
+    }
+    let common = common_items(group);
+    common.single().ok_or_else(|| {
+        first.token().error(format!(
+            "exactly one badge for the group on lines {}-{} (they share {})",
+            first.number,
+            lines[lines.len() - 1].number,
+            describe(common)
+        ))
+    })
+}
+
+fn describe(items: ItemSet) -> String {
+    if items.is_empty() {
+        "nothing".to_string()
+    } else {
+        format!("`{}`", items)
+    }
+}
+
+/// The items carried by every rucksack in `group`, whatever its size.
+pub fn common_items(group: &[Rucksack]) -> ItemSet {
+    items::common(group.iter().map(Rucksack::items))
+}
+
+/// The items common to each successive group of `group_size` rucksacks, which needn't be the
+/// grouping they were parsed with. A trailing group with fewer rucksacks is left out.
+pub fn badges(rucksacks: &[Rucksack], group_size: usize) -> impl Iterator<Item = ItemSet> + '_ {
+    rucksacks.chunks_exact(group_size).map(common_items)
+}
+
+pub fn solve_part1(input: &Input) -> Answer {
+    let priority: u32 = input.rucksacks.iter().map(|rs| rs.shared.priority()).sum();
+    priority.into()
+}
+
+pub fn solve_part2(input: &Input) -> Answer {
+    let priority: u32 = input.badges.iter().map(|badge| badge.priority()).sum();
+    priority.into()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
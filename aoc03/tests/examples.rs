@@ -0,0 +1,20 @@
+use aoc03::{solve_part1, solve_part2, Day03};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day03-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day03>(EXAMPLE)), Answer::from(157));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day03>(EXAMPLE)), Answer::from(70));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day03>();
+}
@@ -0,0 +1,64 @@
+use aoc03::{common_items, parse, parse_with, Item};
+
+const EXAMPLE: &str = include_str!("../../examples/day03-example.txt");
+
+fn item(c: char) -> Item {
+    Item::new(c).unwrap()
+}
+
+#[test]
+fn badges_per_group() {
+    let input = parse(EXAMPLE).unwrap();
+    assert_eq!(input.group_size(), 3);
+    assert_eq!(input.badges(), [item('r'), item('Z')]);
+    let groups = input.groups().collect::<Vec<_>>();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[1].0.len(), 3);
+    assert_eq!(common_items(groups[1].0).single(), Some(groups[1].1));
+    assert_eq!(input.rucksacks()[0].shared(), item('p'));
+}
+
+#[test]
+fn any_group_size() {
+    // The two groups of three have different badges, so as one group they have none.
+    assert!(parse_with(EXAMPLE, 6).is_err());
+    let input = parse_with("aa\nbb\n", 1).unwrap();
+    assert_eq!(input.badges(), [item('a'), item('b')]);
+    let input = parse_with("abca\nbxyb\n", 2).unwrap();
+    assert_eq!(input.badges(), [item('b')]);
+    assert!(parse_with(EXAMPLE, 0).is_err());
+}
+
+#[test]
+fn invalid_rucksacks() {
+    let e = parse("abcab\n").unwrap_err();
+    assert_eq!(e.expected, "an even number of items");
+    let e = parse("abcd\n").unwrap_err();
+    assert_eq!(
+        e.expected,
+        "exactly one item in both compartments (they share nothing)"
+    );
+    let e = parse("abab\n").unwrap_err();
+    assert_eq!(
+        e.expected,
+        "exactly one item in both compartments (they share `ab`)"
+    );
+}
+
+#[test]
+fn invalid_groups() {
+    let e = parse_with("aa\nbb\n", 2).unwrap_err();
+    assert_eq!(
+        e.expected,
+        "exactly one badge for the group on lines 1-2 (they share nothing)"
+    );
+    assert_eq!(e.location.unwrap().line, 1);
+    let e = parse_with("abcab\n", 2).unwrap_err();
+    assert_eq!(e.expected, "an even number of items");
+    let e = parse("aa\naa\naa\nbb\n").unwrap_err();
+    assert_eq!(
+        e.expected,
+        "2 more rucksack(s) to finish the group of 3 starting on line 4"
+    );
+    assert_eq!(e.location.unwrap().line, 4);
+}
@@ -0,0 +1,54 @@
+use aoc03::items::common;
+use aoc03::{badges, common_items, Item, ItemSet};
+
+fn set(items: &str) -> ItemSet {
+    items.chars().map(|c| Item::new(c).unwrap()).collect()
+}
+
+#[test]
+fn priorities() {
+    assert_eq!(Item::new('a').unwrap().priority(), 1);
+    assert_eq!(Item::new('z').unwrap().priority(), 26);
+    assert_eq!(Item::new('A').unwrap().priority(), 27);
+    assert_eq!(Item::new('Z').unwrap().priority(), 52);
+    assert_eq!(Item::new('1'), None);
+    assert_eq!(ItemSet::ALL.len(), 52);
+    assert_eq!(ItemSet::ALL.to_string().len(), 52);
+}
+
+#[test]
+fn set_operations() {
+    let abc = set("abcA");
+    let bcd = set("bcdZ");
+    assert_eq!(abc & bcd, set("bc"));
+    assert_eq!(abc | bcd, set("abcdAZ"));
+    assert_eq!((abc | bcd).to_string(), "abcdAZ");
+    assert!(abc.contains(Item::new('A').unwrap()));
+    assert!(!abc.contains(Item::new('Z').unwrap()));
+    assert_eq!(set("aabb").len(), 2);
+    assert_eq!(set("aZ").priority(), 53);
+    assert!((set("a") & set("b")).is_empty());
+    assert_eq!(common([abc, bcd, set("cz")]), set("c"));
+    assert_eq!(common([]), ItemSet::ALL);
+}
+
+#[test]
+fn single_items() {
+    assert_eq!(set("Q").single(), Item::new('Q'));
+    assert_eq!(set("").single(), None);
+    assert_eq!(set("ab").single(), None);
+}
+
+#[test]
+fn groups_of_any_size() {
+    let input = aoc03::parse_with("abcaXY\nbcXb\nazcz\nxcyc\n", 4).unwrap();
+    let rucksacks = input.rucksacks();
+    assert_eq!(rucksacks[0].shared(), Item::new('a').unwrap());
+    assert_eq!(rucksacks[0].items(), set("abcXY"));
+    assert_eq!(common_items(rucksacks), set("c"));
+    assert_eq!(
+        badges(rucksacks, 2).collect::<Vec<_>>(),
+        [set("bcX"), set("c")]
+    );
+    assert_eq!(badges(rucksacks, 3).collect::<Vec<_>>(), [set("c")]);
+}
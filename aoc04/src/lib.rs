@@ -0,0 +1,74 @@
+use common::interval::{Interval, IntervalSet};
+use common::parse::{lines, Token};
+use common::{Answer, ParseError, Solution};
+
+/// The section assignments for each pair of elves.
+pub type Input = Vec<(Interval, Interval)>;
+
+fn assignment(token: Token) -> Result<Interval, ParseError> {
+    let (start, end) = token.split_once("-")?;
+    let start = start.parse::<i64>("section number")?;
+    let end = end.parse::<i64>("section number")?;
+    Interval::new(start, end)
+        .ok_or_else(|| token.error("a range whose end is not before its start"))
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut pairs = Vec::new();
+    for line in lines(input) {
+        let (first, second) = line.token().split_once(",")?;
+        pairs.push((assignment(first)?, assignment(second)?));
+    }
+    Ok(pairs)
+}
+
+/// Every elf's assignment, pairs and all.
+fn assignments(pairs: &Input) -> impl Iterator<Item = Interval> + '_ {
+    pairs.iter().flat_map(|&(a, b)| [a, b])
+}
+
+/// The sections assigned to at least `k` elves.
+pub fn covered_by_at_least(pairs: &Input, k: usize) -> IntervalSet {
+    IntervalSet::covered_at_least(assignments(pairs), k)
+}
+
+/// The sections nobody was assigned, between the lowest and highest that somebody was.
+pub fn unassigned(pairs: &Input) -> IntervalSet {
+    let assigned = assignments(pairs).collect::<IntervalSet>();
+    match assigned.span() {
+        Some(span) => assigned.gaps(span),
+        None => IntervalSet::new(),
+    }
+}
+
+pub fn solve_part1(pairs: &Input) -> Answer {
+    pairs
+        .iter()
+        .filter(|(r1, r2)| r1.contains_interval(r2) || r2.contains_interval(r1))
+        .count()
+        .into()
+}
+
+pub fn solve_part2(pairs: &Input) -> Answer {
+    pairs
+        .iter()
+        .filter(|(r1, r2)| r1.overlaps(r2))
+        .count()
+        .into()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
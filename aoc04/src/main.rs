@@ -1,21 +1,5 @@
-#[derive(Debug)]
-struct ParseError;
+use std::process::ExitCode;
 
-fn main() {
-    let mut part1_count = 0;
-    let mut part2_count = 0;
-    for line in std::io::stdin().lines() {
-        let line = line.unwrap();
-        let mut tokens = line.split(&[',', '-']);
-        let r1 = std::ops::Range { start: tokens.next().unwrap().parse::<i32>().unwrap(), end: tokens.next().unwrap().parse::<i32>().unwrap() + 1 };
-        let r2 = std::ops::Range { start: tokens.next().unwrap().parse::<i32>().unwrap(), end: tokens.next().unwrap().parse::<i32>().unwrap() + 1 };
-        if r1.contains(&r2.start) && r1.contains(&(r2.end - 1)) || r2.contains(&r1.start) && r2.contains(&(r1.end - 1)) {
-            part1_count += 1;
-        }
-        if r1.contains(&r2.start) || r1.contains(&(r2.end - 1)) || r2.contains(&r1.start) || r2.contains(&(r1.end - 1)) {
-            part2_count += 1;
-        }
-    }
-    println!("Part 1: {}", part1_count);
-    println!("Part 2: {}", part2_count);
+fn main() -> ExitCode {
+    common::main::<aoc04::Day04>()
 }
@@ -0,0 +1,20 @@
+use aoc04::{solve_part1, solve_part2, Day04};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day04-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day04>(EXAMPLE)), Answer::from(2));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day04>(EXAMPLE)), Answer::from(4));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day04>();
+}
@@ -0,0 +1,26 @@
+use aoc04::{covered_by_at_least, parse, unassigned};
+
+const EXAMPLE: &str = include_str!("../../examples/day04-example.txt");
+
+#[test]
+fn coverage() {
+    let pairs = parse(EXAMPLE).unwrap();
+    assert_eq!(covered_by_at_least(&pairs, 1).to_string(), "2-9");
+    assert_eq!(covered_by_at_least(&pairs, 2).len(), 7);
+    assert_eq!(covered_by_at_least(&pairs, 5).to_string(), "3-7");
+    assert_eq!(covered_by_at_least(&pairs, 8).to_string(), "6-6");
+    assert!(covered_by_at_least(&pairs, 9).is_empty());
+}
+
+#[test]
+fn unassigned_sections() {
+    assert!(unassigned(&parse(EXAMPLE).unwrap()).is_empty());
+    let pairs = parse("1-3,10-12\n5-6,2-2\n").unwrap();
+    assert_eq!(unassigned(&pairs).to_string(), "4-4,7-9");
+}
+
+#[test]
+fn backwards_assignment() {
+    let e = parse("4-2,1-1\n").unwrap_err();
+    assert_eq!(e.found.as_deref(), Some("4-2"));
+}
@@ -0,0 +1,180 @@
+pub mod stacks;
+
+use common::parse::{lines, Line};
+use common::{Answer, ParseError, Solution};
+use log::trace;
+use stacks::MoveError;
+pub use stacks::{Crane, CrateMover9000, CrateMover9001, CrateStacks, LimitedCrane, Move};
+
+/// The starting stacks and the rearrangement procedure. Every move is known to be possible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    stacks: CrateStacks,
+    moves: Vec<Move>,
+}
+
+impl Input {
+    pub fn stacks(&self) -> &CrateStacks {
+        &self.stacks
+    }
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+    /// The stacks after `crane` has made every move, with the moves in their history.
+    pub fn rearrange(&self, crane: &dyn Crane) -> CrateStacks {
+        self.rearrange_with(crane, |_, _| {})
+    }
+    /// Like `rearrange`, but calling `frame` with the starting stacks and then again after each
+    /// move, along with the move just made.
+    pub fn rearrange_with(
+        &self,
+        crane: &dyn Crane,
+        mut frame: impl FnMut(Option<Move>, &CrateStacks),
+    ) -> CrateStacks {
+        let mut stacks = self.stacks.clone();
+        frame(None, &stacks);
+        for &m in &self.moves {
+            trace!("{}", m);
+            stacks
+                .apply(crane, m)
+                .expect("moves are checked when parsing");
+            frame(Some(m), &stacks);
+        }
+        stacks
+    }
+}
+
+/// A crane by name: `9000` or `9001` for the CrateMovers, or `max-N` for a crane lifting at most
+/// `N` crates at a time.
+pub fn crane(name: &str) -> Option<Box<dyn Crane>> {
+    match name {
+        "9000" => Some(Box::new(CrateMover9000)),
+        "9001" => Some(Box::new(CrateMover9001)),
+        _ => {
+            let capacity = name.strip_prefix("max-")?.parse().ok()?;
+            Some(Box::new(LimitedCrane::new(capacity)?))
+        }
+    }
+}
+
+/// Reads the drawing up to and including the blank line after its numbered base line.
+fn parse_drawing<'a>(
+    lines: &mut impl Iterator<Item = Line<'a>>,
+) -> Result<CrateStacks, ParseError> {
+    let mut rows = Vec::new();
+    let base = loop {
+        let Some(line) = lines.next() else {
+            return Err(ParseError::new("a line of stack numbers under the drawing"));
+        };
+        if !line.text.contains('[') {
+            break line;
+        }
+        rows.push(line);
+    };
+    let mut count = 0;
+    for token in base.tokens() {
+        count += 1;
+        if token.parse::<usize>("stack number")? != count {
+            return Err(token.error(format!("stack number {}", count)));
+        }
+    }
+    if count == 0 {
+        return Err(base.error_at_end("stack numbers"));
+    }
+
+    let mut stacks = vec![Vec::new(); count];
+    for (height, row) in rows.iter().rev().enumerate() {
+        for (column, c) in row.chars() {
+            // Each stack takes four columns: `[A] `.
+            let (fits, expected) = match (column - 1) % 4 {
+                0 => (c == '[', "`[`"),
+                1 => (c.is_ascii_alphabetic(), "crate letter"),
+                2 => (c == ']', "`]`"),
+                _ => (false, "space between stacks"),
+            };
+            if c == ' ' || fits && !c.is_ascii_alphabetic() {
+                continue;
+            } else if !fits {
+                return Err(row.error_at(column, expected));
+            }
+            let stack = (column - 1) / 4;
+            if stack >= count {
+                return Err(
+                    row.error_at(column, format!("crate above one of the {} stacks", count))
+                );
+            }
+            if stacks[stack].len() != height {
+                return Err(row.error_at(column, "crate resting on another crate"));
+            }
+            stacks[stack].push(c);
+        }
+    }
+    if let Some(line) = lines.next() {
+        if !line.is_empty() {
+            return Err(line.token().error("blank line after the drawing"));
+        }
+    }
+    for (i, stack) in stacks.iter().enumerate() {
+        trace!("{}: {}", i + 1, stack.iter().collect::<String>());
+    }
+    Ok(CrateStacks::new(stacks))
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut lines = lines(input);
+    let stacks = parse_drawing(&mut lines)?;
+    // Only the heights of the stacks matter when checking that every move can be made, and those
+    // don't depend on the crane.
+    let mut check = stacks.clone();
+    let mut moves = Vec::new();
+    for line in lines {
+        let mut tokens = line.tokens();
+        tokens.expect("move")?;
+        let count_token = tokens.next_token("crate count")?;
+        let count = count_token.parse::<usize>("crate count")?;
+        tokens.expect("from")?;
+        let from_token = tokens.next_token("stack number")?;
+        let from = from_token.parse::<usize>("stack number")?;
+        tokens.expect("to")?;
+        let to_token = tokens.next_token("stack number")?;
+        let to = to_token.parse::<usize>("stack number")?;
+        tokens.end()?;
+        let m = Move { count, from, to };
+        check.apply(&CrateMover9000, m).map_err(|e| match e {
+            MoveError::NoSuchStack(stack) => {
+                let token = if stack == from { from_token } else { to_token };
+                token.error(format!("stack number between 1 and {}", check.len()))
+            }
+            MoveError::NotEnoughCrates { stack, has, .. } => count_token.error(format!(
+                "at most {} crate(s), the height of stack {}",
+                has, stack
+            )),
+        })?;
+        moves.push(m);
+    }
+    Ok(Input { stacks, moves })
+}
+
+pub fn solve_part1(input: &Input) -> Answer {
+    input.rearrange(&CrateMover9000).tops().into()
+}
+
+pub fn solve_part2(input: &Input) -> Answer {
+    input.rearrange(&CrateMover9001).tops().into()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
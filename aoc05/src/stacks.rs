@@ -0,0 +1,234 @@
+//! Stacks of crates and the cranes that rearrange them. Every move is recorded along with the
+//! crates it took, so a move can be undone whichever crane made it, and the whole history can be
+//! replayed with a different crane.
+
+use std::fmt;
+
+pub type Crate = char;
+
+/// `move COUNT from FROM to TO`. Stacks are numbered from 1, as in the drawing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from, self.to)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveError {
+    NoSuchStack(usize),
+    NotEnoughCrates {
+        stack: usize,
+        has: usize,
+        needed: usize,
+    },
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::NoSuchStack(stack) => write!(f, "there is no stack {}", stack),
+            MoveError::NotEnoughCrates { stack, has, needed } => write!(
+                f,
+                "stack {} has {} crate(s), not the {} being moved",
+                stack, has, needed
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+/// How a crane carries crates from one stack to another.
+pub trait Crane {
+    /// Moves the top `count` crates of `from` onto `to`. `from` is known to hold at least that
+    /// many.
+    fn transfer(&self, from: &mut Vec<Crate>, to: &mut Vec<Crate>, count: usize);
+}
+
+/// Moves crates one at a time, so they land in reverse order.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn transfer(&self, from: &mut Vec<Crate>, to: &mut Vec<Crate>, count: usize) {
+        to.extend(from.drain(from.len() - count..).rev());
+    }
+}
+
+/// Moves all the crates at once, so they keep their order.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn transfer(&self, from: &mut Vec<Crate>, to: &mut Vec<Crate>, count: usize) {
+        to.extend(from.drain(from.len() - count..));
+    }
+}
+
+/// Lifts at most `capacity` crates at a time, keeping the order within each lift. A capacity of 1
+/// behaves like the CrateMover 9000, and one at least as large as any move like the 9001.
+pub struct LimitedCrane {
+    capacity: usize,
+}
+
+impl LimitedCrane {
+    /// `None` for a capacity of 0, which couldn't move anything.
+    pub fn new(capacity: usize) -> Option<LimitedCrane> {
+        (capacity > 0).then_some(LimitedCrane { capacity })
+    }
+}
+
+impl Crane for LimitedCrane {
+    fn transfer(&self, from: &mut Vec<Crate>, to: &mut Vec<Crate>, mut count: usize) {
+        while count > 0 {
+            let lift = count.min(self.capacity);
+            to.extend(from.drain(from.len() - lift..));
+            count -= lift;
+        }
+    }
+}
+
+/// A move that has been made, and the crates it took off the `from` stack, bottom to top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub mv: Move,
+    pub taken: Vec<Crate>,
+}
+
+/// Stacks of crates, bottom to top, with a history of the moves made on them.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CrateStacks {
+    stacks: Vec<Vec<Crate>>,
+    history: Vec<Step>,
+}
+
+impl CrateStacks {
+    pub fn new(stacks: Vec<Vec<Crate>>) -> CrateStacks {
+        CrateStacks {
+            stacks,
+            history: Vec::new(),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.stacks.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.stacks.is_empty()
+    }
+    /// The crates on stack `stack`, bottom to top.
+    pub fn stack(&self, stack: usize) -> Option<&[Crate]> {
+        let i = stack.checked_sub(1)?;
+        self.stacks.get(i).map(Vec::as_slice)
+    }
+    pub fn stacks(&self) -> &[Vec<Crate>] {
+        &self.stacks
+    }
+    /// The crate on top of each stack, skipping empty ones.
+    pub fn tops(&self) -> String {
+        self.stacks.iter().filter_map(|s| s.last()).collect()
+    }
+    pub fn history(&self) -> &[Step] {
+        &self.history
+    }
+
+    /// Checks that `m` can be made without making it.
+    pub fn check(&self, m: &Move) -> Result<(), MoveError> {
+        for stack in [m.from, m.to] {
+            self.stack(stack).ok_or(MoveError::NoSuchStack(stack))?;
+        }
+        let has = self.stacks[m.from - 1].len();
+        if has < m.count {
+            return Err(MoveError::NotEnoughCrates {
+                stack: m.from,
+                has,
+                needed: m.count,
+            });
+        }
+        Ok(())
+    }
+    /// Makes `m` with `crane`, leaving the stacks untouched if it can't be made.
+    pub fn apply(&mut self, crane: &dyn Crane, m: Move) -> Result<(), MoveError> {
+        self.check(&m)?;
+        let from = &self.stacks[m.from - 1];
+        let taken = from[from.len() - m.count..].to_vec();
+        match self.stacks.get_disjoint_mut([m.from - 1, m.to - 1]) {
+            Ok([from, to]) => crane.transfer(from, to, m.count),
+            Err(_) => {
+                // Taking crates off a stack and putting them back on the same one: the crane
+                // still decides what order they land in.
+                let stack = &mut self.stacks[m.from - 1];
+                let mut lifted = stack.split_off(stack.len() - m.count);
+                crane.transfer(&mut lifted, stack, m.count);
+            }
+        }
+        self.history.push(Step { mv: m, taken });
+        Ok(())
+    }
+    /// Makes each of `moves` in turn, stopping at the first that can't be made and returning its
+    /// index along with the error.
+    pub fn apply_all(
+        &mut self,
+        crane: &dyn Crane,
+        moves: impl IntoIterator<Item = Move>,
+    ) -> Result<(), (usize, MoveError)> {
+        for (i, m) in moves.into_iter().enumerate() {
+            self.apply(crane, m).map_err(|e| (i, e))?;
+        }
+        Ok(())
+    }
+    /// Takes back the last move, returning it, or `None` if there's nothing to undo.
+    pub fn undo(&mut self) -> Option<Move> {
+        let Step { mv, taken } = self.history.pop()?;
+        let to = &mut self.stacks[mv.to - 1];
+        to.truncate(to.len() - mv.count);
+        self.stacks[mv.from - 1].extend(taken);
+        Some(mv)
+    }
+    /// Takes back every move, back to the starting stacks.
+    pub fn rewind(&mut self) {
+        while self.undo().is_some() {}
+    }
+    /// The same moves, made from the same starting stacks with `crane` instead.
+    pub fn replay(&self, crane: &dyn Crane) -> CrateStacks {
+        let mut replay = self.clone();
+        replay.rewind();
+        for step in &self.history {
+            // The same moves were possible before, and the stack heights don't depend on the
+            // crane, so they still are.
+            replay.apply(crane, step.mv).unwrap();
+        }
+        replay
+    }
+}
+
+/// Draws the stacks the way the puzzle input does, tallest stack at the top and the stack numbers
+/// underneath, e.g.
+///
+/// ```text
+///     [D]
+/// [N] [C]
+/// [Z] [M] [P]
+///  1   2   3
+/// ```
+///
+/// Every line is padded to the full width of the drawing, as in the input, so parsing the output
+/// gives back the same stacks.
+impl fmt::Display for CrateStacks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let height = self.stacks.iter().map(Vec::len).max().unwrap_or(0);
+        for row in (0..height).rev() {
+            let cells = self.stacks.iter().map(|stack| match stack.get(row) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            });
+            writeln!(f, "{}", cells.collect::<Vec<_>>().join(" "))?;
+        }
+        let numbers = (1..=self.stacks.len()).map(|n| format!("{:^3}", n));
+        writeln!(f, "{}", numbers.collect::<Vec<_>>().join(" "))
+    }
+}
@@ -0,0 +1,50 @@
+use aoc05::{crane, parse, CrateMover9000, CrateStacks};
+
+const EXAMPLE: &str = include_str!("../../examples/day05-example.txt");
+
+#[test]
+fn draws_the_input_format() {
+    let input = parse(EXAMPLE).unwrap();
+    let drawing = EXAMPLE.split("\n\n").next().unwrap();
+    assert_eq!(input.stacks().to_string(), format!("{}\n", drawing));
+}
+
+#[test]
+fn drawings_parse_back() {
+    let input = parse(EXAMPLE).unwrap();
+    let mut frames = Vec::new();
+    input.rearrange_with(&CrateMover9000, |_, stacks| frames.push(stacks.clone()));
+    assert_eq!(frames.len(), 1 + input.moves().len());
+    for frame in frames {
+        let reparsed = parse(&format!("{}\n", frame)).unwrap();
+        assert_eq!(reparsed.stacks().stacks(), frame.stacks());
+    }
+    assert_eq!(
+        input.rearrange(&CrateMover9000).to_string(),
+        "        [Z]\n        [N]\n        [D]\n[C] [M] [P]\n 1   2   3 \n"
+    );
+}
+
+#[test]
+fn empty_and_wide_drawings() {
+    assert_eq!(
+        CrateStacks::new(vec![vec![], vec![]]).to_string(),
+        " 1   2 \n"
+    );
+    let ten = CrateStacks::new(vec![vec!['A']; 10]);
+    let drawing = ten.to_string();
+    assert!(drawing.ends_with(" 9  10 \n"));
+    assert_eq!(parse(&format!("{}\n", drawing)).unwrap().stacks(), &ten);
+}
+
+#[test]
+fn cranes_by_name() {
+    let input = parse(EXAMPLE).unwrap();
+    let tops = |name| input.rearrange(crane(name).unwrap().as_ref()).tops();
+    assert_eq!(tops("9000"), "CMZ");
+    assert_eq!(tops("9001"), "MCD");
+    assert_eq!(tops("max-1"), "CMZ");
+    assert_eq!(tops("max-3"), "MCD");
+    assert!(crane("max-0").is_none());
+    assert!(crane("9002").is_none());
+}
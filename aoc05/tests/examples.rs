@@ -0,0 +1,26 @@
+use aoc05::{solve_part1, solve_part2, Day05};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day05-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(
+        solve_part1(&parse::<Day05>(EXAMPLE)),
+        Answer::from("CMZ".to_string())
+    );
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(
+        solve_part2(&parse::<Day05>(EXAMPLE)),
+        Answer::from("MCD".to_string())
+    );
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day05>();
+}
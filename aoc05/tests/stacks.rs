@@ -0,0 +1,105 @@
+use aoc05::stacks::{MoveError, Step};
+use aoc05::{parse, CrateMover9000, CrateMover9001, CrateStacks, LimitedCrane, Move};
+
+const EXAMPLE: &str = include_str!("../../examples/day05-example.txt");
+
+fn stacks(stacks: &[&str]) -> CrateStacks {
+    CrateStacks::new(stacks.iter().map(|s| s.chars().collect()).collect())
+}
+
+fn mv(count: usize, from: usize, to: usize) -> Move {
+    Move { count, from, to }
+}
+
+#[test]
+fn cranes() {
+    let start = stacks(&["ABCD", ""]);
+    let mut s = start.clone();
+    s.apply(&CrateMover9000, mv(3, 1, 2)).unwrap();
+    assert_eq!(s.stack(2), Some(&['D', 'C', 'B'][..]));
+    let mut s = start.clone();
+    s.apply(&CrateMover9001, mv(3, 1, 2)).unwrap();
+    assert_eq!(s.stack(2), Some(&['B', 'C', 'D'][..]));
+    let mut s = start.clone();
+    s.apply(&LimitedCrane::new(2).unwrap(), mv(3, 1, 2))
+        .unwrap();
+    assert_eq!(s.stack(2), Some(&['C', 'D', 'B'][..]));
+    assert!(LimitedCrane::new(0).is_none());
+    let mut s = start.clone();
+    s.apply(&CrateMover9000, mv(2, 1, 1)).unwrap();
+    assert_eq!(s.stack(1), Some(&['A', 'B', 'D', 'C'][..]));
+}
+
+#[test]
+fn invalid_moves_leave_the_stacks_alone() {
+    let mut s = stacks(&["AB", "C"]);
+    assert_eq!(
+        s.apply(&CrateMover9000, mv(3, 1, 2)),
+        Err(MoveError::NotEnoughCrates {
+            stack: 1,
+            has: 2,
+            needed: 3
+        })
+    );
+    assert_eq!(
+        s.apply(&CrateMover9000, mv(1, 1, 3)),
+        Err(MoveError::NoSuchStack(3))
+    );
+    assert_eq!(
+        s.apply(&CrateMover9000, mv(1, 0, 1)),
+        Err(MoveError::NoSuchStack(0))
+    );
+    assert_eq!(s, stacks(&["AB", "C"]));
+    assert_eq!(
+        s.apply_all(&CrateMover9000, [mv(1, 1, 2), mv(3, 2, 1)]),
+        Err((
+            1,
+            MoveError::NotEnoughCrates {
+                stack: 2,
+                has: 2,
+                needed: 3
+            }
+        ))
+    );
+    assert_eq!(s.history().len(), 1);
+}
+
+#[test]
+fn undo_and_replay() {
+    let input = parse(EXAMPLE).unwrap();
+    let mut s = input.rearrange(&CrateMover9000);
+    assert_eq!(s.tops(), "CMZ");
+    assert_eq!(s.history().len(), 4);
+    assert_eq!(
+        s.history()[0],
+        Step {
+            mv: mv(1, 2, 1),
+            taken: vec!['D']
+        }
+    );
+    assert_eq!(s.replay(&CrateMover9001).tops(), "MCD");
+    assert_eq!(s.undo(), Some(mv(1, 1, 2)));
+    assert_eq!(s.tops(), "MZ");
+    s.rewind();
+    assert_eq!(&s, input.stacks());
+    assert_eq!(s.undo(), None);
+}
+
+#[test]
+fn parse_errors() {
+    let e = parse("[A]\n 1 \n\nmove 2 from 1 to 1\n").unwrap_err();
+    assert_eq!(e.expected, "at most 1 crate(s), the height of stack 1");
+    assert_eq!(e.location.unwrap().line, 4);
+    let e = parse("[A]\n 1 \n\nmove 1 from 1 to 2\n").unwrap_err();
+    assert_eq!(e.expected, "stack number between 1 and 1");
+    let e = parse("[A]\n 1 \n\nmove 1 from 1 to 1\nmove 1 from 1 to 1\n");
+    assert!(e.is_ok());
+    let e = parse("[A] [B]\n 1 \n\n").unwrap_err();
+    assert_eq!(e.expected, "crate above one of the 1 stacks");
+    let e = parse("[A]\n    [B]\n 1   2 \n\n").unwrap_err();
+    assert_eq!(e.expected, "crate resting on another crate");
+    let e = parse("[A}\n 1 \n\n").unwrap_err();
+    assert_eq!(e.expected, "`]`");
+    let e = parse("[A]\n 1   3 \n\n").unwrap_err();
+    assert_eq!(e.expected, "stack number 2");
+}
@@ -0,0 +1,53 @@
+pub mod marker;
+
+use common::parse::lines;
+use common::{Answer, ParseError, Solution};
+pub use marker::{first_marker, markers};
+
+/// The datastream buffer, as bytes.
+pub type Input = Vec<u8>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    match lines(input).next() {
+        Some(line) => line
+            .chars()
+            .map(|(column, c)| match c.is_ascii_lowercase() {
+                true => Ok(c as u8),
+                false => Err(line.error_at(column, "lowercase letter")),
+            })
+            .collect(),
+        None => Err(ParseError::new("a datastream buffer")),
+    }
+}
+
+/// The marker's position, or says there isn't one; `Answer::None` would read as a part the
+/// puzzle doesn't have.
+fn answer(position: Option<usize>) -> Answer {
+    position.map_or_else(|| Answer::Text("no marker".to_string()), Answer::from)
+}
+
+/// Where the first start-of-packet marker ends.
+pub fn solve_part1(buffer: &Input) -> Answer {
+    answer(first_marker(buffer.iter().copied(), 4))
+}
+
+/// Where the first start-of-message marker ends.
+pub fn solve_part2(buffer: &Input) -> Answer {
+    answer(first_marker(buffer.iter().copied(), 14))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
@@ -0,0 +1,100 @@
+//! Finding markers, runs of `size` bytes that are all different, in a stream of bytes. The
+//! detector sees each byte once and keeps a count of every byte value in the current window, so
+//! the whole stream is scanned in linear time whatever the window size.
+
+use std::collections::VecDeque;
+use std::io::{self, BufReader, Read};
+
+/// Tracks the last `size` bytes pushed, and whether they are all different.
+#[derive(Debug, Clone)]
+pub struct Detector {
+    size: usize,
+    window: VecDeque<u8>,
+    counts: [usize; 256],
+    /// How many byte values appear in the window at least once.
+    distinct: usize,
+    /// How many bytes have been pushed in all.
+    position: usize,
+}
+
+impl Detector {
+    /// A detector for markers of `size` bytes, which must be at least 1.
+    pub fn new(size: usize) -> Detector {
+        assert!(size > 0, "a marker needs at least one byte");
+        Detector {
+            size,
+            window: VecDeque::with_capacity(size),
+            counts: [0; 256],
+            distinct: 0,
+            position: 0,
+        }
+    }
+    /// Adds the next byte of the stream, and returns whether it completes a marker.
+    pub fn push(&mut self, byte: u8) -> bool {
+        if self.window.len() == self.size {
+            let old = self.window.pop_front().unwrap();
+            self.counts[old as usize] -= 1;
+            if self.counts[old as usize] == 0 {
+                self.distinct -= 1;
+            }
+        }
+        self.window.push_back(byte);
+        self.counts[byte as usize] += 1;
+        if self.counts[byte as usize] == 1 {
+            self.distinct += 1;
+        }
+        self.position += 1;
+        self.distinct == self.size
+    }
+    /// How many bytes have been pushed, which is where the puzzle says a marker ends.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+/// The position after each marker in `bytes`, in order. Markers can overlap.
+pub fn markers(bytes: impl IntoIterator<Item = u8>, size: usize) -> impl Iterator<Item = usize> {
+    let mut detector = Detector::new(size);
+    bytes
+        .into_iter()
+        .filter_map(move |byte| detector.push(byte).then_some(detector.position()))
+}
+
+/// The position after the first marker in `bytes`, if there is one.
+pub fn first_marker(bytes: impl IntoIterator<Item = u8>, size: usize) -> Option<usize> {
+    markers(bytes, size).next()
+}
+
+/// Like `markers`, but reading the stream from `reader`. Every byte counts, newlines included, so
+/// a datastream file's trailing newline is part of the stream; `crate::parse` is the place that
+/// takes just the first line.
+pub fn read_markers(reader: impl Read, size: usize) -> io::Result<Vec<usize>> {
+    let mut found = Vec::new();
+    scan(reader, size, |position| {
+        found.push(position);
+        true
+    })?;
+    Ok(found)
+}
+
+/// Like `first_marker`, but reading the stream from `reader`, and stopping as soon as a marker is
+/// found.
+pub fn read_first_marker(reader: impl Read, size: usize) -> io::Result<Option<usize>> {
+    let mut first = None;
+    scan(reader, size, |position| {
+        first = Some(position);
+        false
+    })?;
+    Ok(first)
+}
+
+/// Calls `found` with the position of each marker until it returns false or the stream ends.
+fn scan(reader: impl Read, size: usize, mut found: impl FnMut(usize) -> bool) -> io::Result<()> {
+    let mut detector = Detector::new(size);
+    for byte in BufReader::new(reader).bytes() {
+        if detector.push(byte?) && !found(detector.position()) {
+            break;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use aoc06::{solve_part1, solve_part2, Day06};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day06-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day06>(EXAMPLE)), Answer::from(7));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day06>(EXAMPLE)), Answer::from(19));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day06>();
+}
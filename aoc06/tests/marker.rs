@@ -0,0 +1,78 @@
+use aoc06::marker::{read_first_marker, read_markers, Detector};
+use aoc06::{first_marker, markers};
+use common::Answer;
+
+const STREAMS: [(&str, usize, usize); 4] = [
+    ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+    ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+    ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+    ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+];
+
+#[test]
+fn puzzle_examples() {
+    for (stream, packet, message) in STREAMS {
+        assert_eq!(first_marker(stream.bytes(), 4), Some(packet));
+        assert_eq!(first_marker(stream.bytes(), 14), Some(message));
+        assert_eq!(
+            read_first_marker(stream.as_bytes(), 4).unwrap(),
+            Some(packet)
+        );
+    }
+}
+
+#[test]
+fn no_marker() {
+    assert_eq!(first_marker("aabbaabb".bytes(), 3), None);
+    assert_eq!(first_marker("abc".bytes(), 4), None);
+    assert_eq!(read_first_marker(&b""[..], 1).unwrap(), None);
+
+    let buffer = aoc06::parse("abcabc\n").unwrap();
+    let none = Answer::Text("no marker".to_string());
+    assert_eq!(aoc06::solve_part1(&buffer), none);
+    assert_eq!(aoc06::solve_part2(&buffer), none);
+}
+
+#[test]
+fn markers_at_the_very_start() {
+    assert_eq!(first_marker("abcd".bytes(), 4), Some(4));
+    assert_eq!(first_marker("a".bytes(), 1), Some(1));
+}
+
+#[test]
+fn every_marker() {
+    assert_eq!(
+        markers("abcabbcd".bytes(), 3).collect::<Vec<_>>(),
+        [3, 4, 5, 8]
+    );
+}
+
+#[test]
+fn readers_see_the_whole_stream() {
+    let stream = b"abcabbcd\nxyz\n\0\n\xff";
+    let expected = markers(stream.iter().copied(), 3).collect::<Vec<_>>();
+    assert_eq!(expected, [3, 4, 5, 8, 9, 10, 11, 12, 13, 14, 16]);
+    assert_eq!(read_markers(&stream[..], 3).unwrap(), expected);
+    assert_eq!(read_first_marker(&b"aa\nb"[..], 2).unwrap(), Some(3));
+}
+
+#[test]
+fn large_windows_and_streams() {
+    // Every byte value once, then the same again: each window of 256 is a marker.
+    let stream = (0..=255u8).chain(0..=255).collect::<Vec<_>>();
+    assert_eq!(first_marker(stream.iter().copied(), 256), Some(256));
+    assert_eq!(markers(stream.iter().copied(), 256).count(), 257);
+    assert_eq!(first_marker(stream.iter().copied(), 257), None);
+
+    let long = std::iter::repeat_n(b'x', 1_000_000).chain([b'y']);
+    assert_eq!(markers(long, 2).collect::<Vec<_>>(), [1_000_001]);
+}
+
+#[test]
+fn detector_positions() {
+    let mut detector = Detector::new(2);
+    assert!(!detector.push(b'a'));
+    assert!(!detector.push(b'a'));
+    assert!(detector.push(b'b'));
+    assert_eq!(detector.position(), 3);
+}
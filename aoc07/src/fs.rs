@@ -0,0 +1,314 @@
+//! An in-memory filesystem tree. Nodes live in an arena and refer to each other by `NodeId`, with
+//! a link back to their parent, and every directory caches the total size of everything under it.
+//! Adding a file updates the totals on the way up to the root, so no size is ever recomputed.
+
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Kind {
+    Directory {
+        /// Entries in the order they were added.
+        children: Vec<NodeId>,
+        /// The same entries by name, so looking one up doesn't scan the directory.
+        names: HashMap<String, NodeId>,
+    },
+    File,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    name: String,
+    parent: Option<NodeId>,
+    kind: Kind,
+    /// A file's size, or the total size of the files anywhere under a directory.
+    size: u64,
+}
+
+impl Node {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// `None` only for the root.
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+    pub fn kind(&self) -> &Kind {
+        &self.kind
+    }
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, Kind::Directory { .. })
+    }
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+    /// A directory's entries, in the order they were added. Files have none.
+    pub fn children(&self) -> &[NodeId] {
+        match &self.kind {
+            Kind::Directory { children, .. } => children,
+            Kind::File => &[],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FsError {
+    /// Nothing at this path.
+    NotFound(String),
+    /// A path went through a file as if it were a directory.
+    NotADirectory(String),
+    /// A file was added where there's already a directory of that name.
+    IsADirectory(String),
+    /// A file was added again with a different size.
+    SizeConflict { path: String, old: u64, new: u64 },
+    /// Adding a file would take the total size past what a `u64` can hold.
+    TooLarge { path: String, size: u64 },
+}
+
+impl fmt::Display for FsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FsError::NotFound(path) => write!(f, "{} doesn't exist", path),
+            FsError::NotADirectory(path) => write!(f, "{} is a file, not a directory", path),
+            FsError::IsADirectory(path) => write!(f, "{} is a directory, not a file", path),
+            FsError::SizeConflict { path, old, new } => {
+                write!(f, "{} has size {}, not {}", path, old, new)
+            }
+            FsError::TooLarge { path, size } => write!(
+                f,
+                "adding {} of size {} would take the total past {}",
+                path,
+                size,
+                u64::MAX
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FsError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+    cwd: NodeId,
+}
+
+impl Default for FileSystem {
+    fn default() -> FileSystem {
+        FileSystem::new()
+    }
+}
+
+impl FileSystem {
+    const ROOT: NodeId = NodeId(0);
+
+    /// An empty filesystem, in the root directory.
+    pub fn new() -> FileSystem {
+        FileSystem {
+            nodes: vec![Node {
+                name: String::new(),
+                parent: None,
+                kind: Kind::Directory {
+                    children: Vec::new(),
+                    names: HashMap::new(),
+                },
+                size: 0,
+            }],
+            cwd: FileSystem::ROOT,
+        }
+    }
+    pub fn root(&self) -> NodeId {
+        FileSystem::ROOT
+    }
+    pub fn cwd(&self) -> NodeId {
+        self.cwd
+    }
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    /// The absolute path of `id`, e.g. `/a/e`.
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut node = id;
+        while let Some(parent) = self.node(node).parent {
+            names.push(self.node(node).name.as_str());
+            node = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+    /// The entry called `name` in directory `dir`.
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        match &self.node(dir).kind {
+            Kind::Directory { names, .. } => names.get(name).copied(),
+            Kind::File => None,
+        }
+    }
+    /// Follows `path` from the current directory, or from the root if it starts with `/`. `..`
+    /// goes up a level, but not past the root, as in a shell, and `.` and empty segments stay
+    /// put.
+    pub fn resolve(&self, path: &str) -> Result<NodeId, FsError> {
+        let mut node = if path.starts_with('/') {
+            FileSystem::ROOT
+        } else {
+            self.cwd
+        };
+        for segment in path.split('/') {
+            self.expect_dir(node)?;
+            node = match segment {
+                "" | "." => node,
+                ".." => self.node(node).parent.unwrap_or(node),
+                name => self
+                    .child(node, name)
+                    .ok_or_else(|| FsError::NotFound(self.join(node, name)))?,
+            };
+        }
+        Ok(node)
+    }
+    /// Changes the current directory to `path`, which must already exist.
+    pub fn cd(&mut self, path: &str) -> Result<NodeId, FsError> {
+        let dir = self.resolve(path)?;
+        self.set_cwd(dir)?;
+        Ok(dir)
+    }
+    pub fn set_cwd(&mut self, dir: NodeId) -> Result<(), FsError> {
+        self.expect_dir(dir)?;
+        self.cwd = dir;
+        Ok(())
+    }
+    fn expect_dir(&self, id: NodeId) -> Result<(), FsError> {
+        match self.node(id).is_dir() {
+            true => Ok(()),
+            false => Err(FsError::NotADirectory(self.path(id))),
+        }
+    }
+    /// Adds a directory called `name` to `parent`, or returns the one already there.
+    pub fn mkdir(&mut self, parent: NodeId, name: &str) -> Result<NodeId, FsError> {
+        self.expect_dir(parent)?;
+        match self.child(parent, name) {
+            Some(existing) if self.node(existing).is_dir() => Ok(existing),
+            Some(existing) => Err(FsError::NotADirectory(self.path(existing))),
+            None => Ok(self.add_node(
+                parent,
+                name,
+                Kind::Directory {
+                    children: Vec::new(),
+                    names: HashMap::new(),
+                },
+                0,
+            )),
+        }
+    }
+    /// Like `resolve`, but creating any directories along `path` that don't exist yet.
+    pub fn mkdir_all(&mut self, path: &str) -> Result<NodeId, FsError> {
+        let mut node = if path.starts_with('/') {
+            FileSystem::ROOT
+        } else {
+            self.cwd
+        };
+        for segment in path.split('/') {
+            node = match segment {
+                "" | "." => node,
+                ".." => self.node(node).parent.unwrap_or(node),
+                name => self.mkdir(node, name)?,
+            };
+        }
+        Ok(node)
+    }
+    /// Adds a file to `parent`. Adding the same file again with the same size changes nothing,
+    /// and a file that would take the total size past `u64::MAX` isn't added.
+    pub fn add_file(&mut self, parent: NodeId, name: &str, size: u64) -> Result<NodeId, FsError> {
+        self.expect_dir(parent)?;
+        if let Some(existing) = self.child(parent, name) {
+            let node = self.node(existing);
+            return match node.kind {
+                Kind::Directory { .. } => Err(FsError::IsADirectory(self.path(existing))),
+                Kind::File if node.size == size => Ok(existing),
+                Kind::File => Err(FsError::SizeConflict {
+                    path: self.path(existing),
+                    old: node.size,
+                    new: size,
+                }),
+            };
+        }
+        // The root's total is the largest, so if it doesn't overflow, no directory's does.
+        if self.node(FileSystem::ROOT).size.checked_add(size).is_none() {
+            return Err(FsError::TooLarge {
+                path: self.join(parent, name),
+                size,
+            });
+        }
+        let id = self.add_node(parent, name, Kind::File, size);
+        let mut dir = Some(parent);
+        while let Some(d) = dir {
+            self.nodes[d.0].size += size;
+            dir = self.node(d).parent;
+        }
+        Ok(id)
+    }
+    fn add_node(&mut self, parent: NodeId, name: &str, kind: Kind, size: u64) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            name: name.to_string(),
+            parent: Some(parent),
+            kind,
+            size,
+        });
+        match &mut self.nodes[parent.0].kind {
+            Kind::Directory { children, names } => {
+                children.push(id);
+                names.insert(name.to_string(), id);
+            }
+            Kind::File => unreachable!("checked by the caller"),
+        }
+        id
+    }
+    fn join(&self, dir: NodeId, name: &str) -> String {
+        match self.node(dir).parent {
+            None => format!("/{}", name),
+            Some(_) => format!("{}/{}", self.path(dir), name),
+        }
+    }
+
+    /// Every node under `top`, `top` included, parents before their children and siblings in
+    /// the order they were added.
+    pub fn walk(&self, top: NodeId) -> Walk<'_> {
+        Walk {
+            fs: self,
+            stack: vec![(top, 0)],
+        }
+    }
+    /// Every directory, the root first.
+    pub fn directories(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.walk(FileSystem::ROOT)
+            .map(|(id, _)| id)
+            .filter(|&id| self.node(id).is_dir())
+    }
+    /// Every node called `name`, in walk order.
+    pub fn find<'a>(&'a self, name: &'a str) -> impl Iterator<Item = NodeId> + 'a {
+        self.walk(FileSystem::ROOT)
+            .map(|(id, _)| id)
+            .filter(move |&id| self.node(id).name == name)
+    }
+}
+
+/// A depth-first walk of the tree, yielding each node along with its depth below the top.
+pub struct Walk<'a> {
+    fs: &'a FileSystem,
+    stack: Vec<(NodeId, usize)>,
+}
+
+impl Iterator for Walk<'_> {
+    type Item = (NodeId, usize);
+    fn next(&mut self) -> Option<(NodeId, usize)> {
+        let (id, depth) = self.stack.pop()?;
+        let children = self.fs.node(id).children();
+        self.stack
+            .extend(children.iter().rev().map(|&c| (c, depth + 1)));
+        Some((id, depth))
+    }
+}
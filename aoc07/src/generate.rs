@@ -0,0 +1,86 @@
+//! Going the other way: writing out the terminal transcript that would rebuild a filesystem,
+//! whether one reconstructed from a transcript or one read from a real directory on disk.
+
+use crate::fs::{FileSystem, Kind, NodeId};
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+/// A transcript in the puzzle's format that explores the whole of a filesystem: each directory is
+/// entered with `cd`, listed with `ls` once, and left with `cd ..` after its subdirectories, which
+/// are visited in the order they were added. Parsing it gives back the same tree.
+pub struct Transcript<'a>(pub &'a FileSystem);
+
+/// What the transcript does next: explore a directory, or go back up from one.
+enum Step {
+    Enter(NodeId),
+    Leave,
+}
+
+impl fmt::Display for Transcript<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fs = self.0;
+        // An explicit stack rather than recursion, so deep trees can't overflow the real one.
+        let mut steps = vec![Step::Enter(fs.root())];
+        while let Some(step) = steps.pop() {
+            let dir = match step {
+                Step::Enter(dir) => dir,
+                Step::Leave => {
+                    writeln!(f, "$ cd ..")?;
+                    continue;
+                }
+            };
+            match fs.node(dir).parent() {
+                None => writeln!(f, "$ cd /")?,
+                Some(_) => writeln!(f, "$ cd {}", fs.node(dir).name())?,
+            }
+            writeln!(f, "$ ls")?;
+            let children = fs.node(dir).children();
+            for &child in children {
+                let node = fs.node(child);
+                match node.kind() {
+                    Kind::Directory { .. } => writeln!(f, "dir {}", node.name())?,
+                    Kind::File => writeln!(f, "{} {}", node.size(), node.name())?,
+                }
+            }
+            for &child in children.iter().rev() {
+                if fs.node(child).is_dir() {
+                    steps.push(Step::Leave);
+                    steps.push(Step::Enter(child));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads the directory `top` on disk, and everything under it, into a filesystem rooted there.
+/// Entries are added in name order. Symbolic links and other special files are left out, as are
+/// names a transcript can't hold: those that aren't UTF-8 or that contain whitespace.
+pub fn from_disk(top: &Path) -> io::Result<FileSystem> {
+    let mut fs = FileSystem::new();
+    let mut pending = vec![(top.to_path_buf(), fs.root())];
+    while let Some((path, dir)) = pending.pop() {
+        let mut entries = std::fs::read_dir(&path)?.collect::<io::Result<Vec<_>>>()?;
+        entries.sort_by_key(|entry| entry.file_name());
+        for entry in entries {
+            let Some(name) = entry.file_name().to_str().map(str::to_string) else {
+                continue;
+            };
+            if name.contains(char::is_whitespace) {
+                continue;
+            }
+            // A directory can't hold two entries with the same name, so neither of these can
+            // clash with anything already added.
+            let kind = entry.file_type()?;
+            if kind.is_dir() {
+                let child = fs.mkdir(dir, &name).unwrap();
+                pending.push((entry.path(), child));
+            } else if kind.is_file() {
+                let size = entry.metadata()?.len();
+                fs.add_file(dir, &name, size).unwrap();
+            }
+        }
+    }
+    Ok(fs)
+}
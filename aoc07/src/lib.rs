@@ -0,0 +1,71 @@
+pub mod fs;
+pub mod generate;
+pub mod plan;
+pub mod report;
+pub mod transcript;
+
+use common::{Answer, ParseError, Solution};
+pub use fs::{FileSystem, FsError, Node, NodeId};
+pub use plan::{Disk, Plan};
+
+pub type Input = FileSystem;
+
+/// Rebuilds the filesystem from a terminal transcript. `cd` may take any absolute or relative
+/// path. Untidy transcripts are accepted as long as they don't contradict themselves; see
+/// `transcript` for what that means, and `validate` to hear about everything.
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let (filesystem, problems) = transcript::replay(input)?;
+    match problems.into_iter().find(|p| p.fatal) {
+        Some(problem) => Err(problem.error),
+        None => Ok(filesystem),
+    }
+}
+
+/// Like `parse`, but rejecting the transcript if anything at all is wrong with it, and reporting
+/// every problem in it, in order.
+pub fn validate(input: &str) -> Result<Input, Vec<ParseError>> {
+    let (filesystem, problems) = transcript::replay(input).map_err(|e| vec![e])?;
+    if problems.is_empty() {
+        Ok(filesystem)
+    } else {
+        Err(problems.into_iter().map(|p| p.error).collect())
+    }
+}
+
+/// The sizes of every directory, the root first.
+fn directory_sizes(filesystem: &Input) -> impl Iterator<Item = u64> + '_ {
+    filesystem
+        .directories()
+        .map(|dir| filesystem.node(dir).size())
+}
+
+pub fn solve_part1(filesystem: &Input) -> Answer {
+    directory_sizes(filesystem)
+        .filter(|&size| size < 100000)
+        .sum::<u64>()
+        .into()
+}
+
+pub fn solve_part2(filesystem: &Input) -> Answer {
+    let plan = Plan::new(filesystem, Disk::PUZZLE);
+    match plan.smallest() {
+        Some(dir) => filesystem.node(dir).size().into(),
+        None => Answer::None,
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
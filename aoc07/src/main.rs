@@ -1,122 +1,128 @@
-use std::collections::HashMap;
+use aoc07::generate::{from_disk, Transcript};
+use aoc07::report::{human_size, Du, Tree};
+use aoc07::{Disk, FileSystem, Plan};
+use std::path::Path;
+use std::process::ExitCode;
 
-struct File {
-    name: String,
-    size: i32,
-}
-struct Directory {
-    path: Path,
-    files: Vec<File>,
-    directories: Vec<String>,
-}
-impl Directory {
-    fn new(path: Path) -> Directory {
-        Directory {
-            path: path,
-            files: Vec::new(),
-            directories: Vec::new(),
+/// `aoc07 --check < TRANSCRIPT` reports every problem in the transcript rather than solving the
+/// puzzle, and fails if there are any.
+fn check() -> ExitCode {
+    match aoc07::validate(&common::read_stdin()) {
+        Ok(filesystem) => {
+            let dirs = filesystem.directories().count();
+            println!("ok: {} directories", dirs);
+            ExitCode::SUCCESS
+        }
+        Err(problems) => {
+            for problem in &problems {
+                eprintln!("{}\n", problem.pretty("<stdin>"));
+            }
+            eprintln!("{} problem(s)", problems.len());
+            ExitCode::FAILURE
         }
     }
-    fn size(&self, fs: &FileSystem) -> i32 {
-        let fsize: i32 = self.files.iter().map(|f| f.size).sum();
-        let dsize: i32 = self
-            .directories
-            .iter()
-            .map(|d| {
-                let mut p = self.path.clone();
-                p.path.push(d.clone());
-                fs.directories[&p.as_string()].size(fs)
-            })
-            .sum();
-        fsize + dsize
-    }
-}
-#[derive(Clone)]
-struct Path {
-    path: Vec<String>,
 }
-impl Path {
-    fn new() -> Path {
-        Path { path: Vec::new() }
+
+/// `aoc07 --plan [CAPACITY NEEDED] < TRANSCRIPT` lists every directory that would free enough
+/// space on its own, and the least that can be deleted to free enough. The disk defaults to the
+/// puzzle's.
+fn plan(filesystem: &FileSystem, args: &[String]) -> Result<(), String> {
+    let disk = match args {
+        [] => Disk::PUZZLE,
+        [capacity, needed] => {
+            let size = |arg: &String| {
+                arg.parse::<u64>()
+                    .map_err(|_| format!("expected a size in bytes, found `{}`", arg))
+            };
+            Disk {
+                capacity: size(capacity)?,
+                needed: size(needed)?,
+            }
+        }
+        _ => return Err("usage: aoc07 --plan [CAPACITY NEEDED]".to_string()),
+    };
+    let plan = Plan::new(filesystem, disk);
+    let describe = |dir| {
+        let size = filesystem.node(dir).size();
+        format!("{}\t{}", human_size(size), filesystem.path(dir))
+    };
+    println!("to free: {}", human_size(plan.to_free));
+    println!("\ncandidates:");
+    for &dir in &plan.candidates {
+        println!("{}", describe(dir));
     }
-    fn as_string(&self) -> String {
-        let mut s = String::from("/");
-        s.push_str(self.path.join("/").as_str());
-        s
+    match plan.best(filesystem) {
+        Some(best) => {
+            let exact = match best.exact {
+                true => "",
+                false => " (maybe not the least possible)",
+            };
+            println!(
+                "\nbest: {} in {} directories{}",
+                human_size(best.size),
+                best.dirs.len(),
+                exact
+            );
+            for &dir in &best.dirs {
+                println!("{}", describe(dir));
+            }
+        }
+        None => println!("\nbest: not even deleting everything would free enough"),
     }
+    Ok(())
 }
-struct FileSystem {
-    cwd: Path,
-    directories: HashMap<String, Directory>,
-}
-impl FileSystem {
-    fn new() -> FileSystem {
-        FileSystem {
-            cwd: Path { path: Vec::new() },
-            directories: HashMap::from([(String::from("/"), Directory::new(Path::new()))]),
+
+/// `aoc07 --generate DIR` prints a transcript that explores the directory `DIR` on disk.
+fn generate(dir: &str) -> ExitCode {
+    match from_disk(Path::new(dir)) {
+        Ok(filesystem) => {
+            print!("{}", Transcript(&filesystem));
+            ExitCode::SUCCESS
         }
-    }
-    fn cd(&mut self, name: &str) {
-        if name == "/" {
-            self.cwd.path.clear();
-        } else if name == ".." {
-            self.cwd.path.pop();
-        } else {
-            self.cwd.path.push(String::from(name));
+        Err(e) => {
+            eprintln!("{}: {}", dir, e);
+            ExitCode::FAILURE
         }
     }
-    fn add_file(&mut self, name: String, size: i32) {
-        self.directories
-            .get_mut(&self.cwd.as_string())
-            .unwrap()
-            .files
-            .push(File {
-                name: name,
-                size: size,
-            });
-    }
-    fn add_directory(&mut self, name: String) {
-        let mut path = self.cwd.clone();
-        path.path.push(name.clone());
-        self.directories
-            .insert(path.as_string(), Directory::new(path));
-        self.directories
-            .get_mut(&self.cwd.as_string())
-            .unwrap()
-            .directories
-            .push(name);
-    }
 }
 
-fn main() {
-    let mut filesystem = FileSystem::new();
-    for line in std::io::stdin().lines() {
-        let line = line.unwrap();
-        let tokens: Vec<&str> = line.split_ascii_whitespace().collect();
-        if tokens[0] == "$" {
-            if tokens[1] == "cd" {
-                filesystem.cd(tokens[2]);
+fn report(mode: &str, args: &[String]) -> ExitCode {
+    let filesystem = match aoc07::parse(&common::read_stdin()) {
+        Ok(filesystem) => filesystem,
+        Err(e) => {
+            eprintln!("{}", e.pretty("<stdin>"));
+            return ExitCode::FAILURE;
+        }
+    };
+    match mode {
+        "--tree" => print!("{}", Tree(&filesystem)),
+        "--generate" => print!("{}", Transcript(&filesystem)),
+        "--du" => print!(
+            "{}",
+            Du {
+                fs: &filesystem,
+                human: args.first().map(String::as_str) == Some("-h"),
             }
-        } else {
-            if tokens[0] == "dir" {
-                filesystem.add_directory(tokens[1].to_string());
-            } else {
-                filesystem.add_file(tokens[1].to_string(), tokens[0].parse::<i32>().unwrap());
+        ),
+        _ => {
+            if let Err(e) = plan(&filesystem, args) {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
             }
         }
     }
-    let mut total_size_under_100k = 0;
-    let mut free = 70000000 - filesystem.directories["/"].size(&filesystem);
-    let mut smallest_size = 0;
-    for dir in filesystem.directories.values() {
-        let size = dir.size(&filesystem);
-        if size < 100000 {
-            total_size_under_100k += size;
-        }
-        if free + size >= 30000000 && (smallest_size == 0 || size < smallest_size) {
-            smallest_size = size;
-        }
+    ExitCode::SUCCESS
+}
+
+/// Besides `--check`, `--tree` and `--du [-h]` print the filesystem a transcript describes, and
+/// `--plan` works out what to delete. `--generate` with no directory rewrites the transcript on
+/// stdin in a tidy form.
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match args.first().map(String::as_str) {
+        Some("--check") => check(),
+        Some("--generate") if args.len() == 2 => generate(&args[1]),
+        Some(mode @ ("--tree" | "--du" | "--plan" | "--generate")) => report(mode, &args[1..]),
+        _ => common::main::<aoc07::Day07>(),
     }
-    println!("Part 1: {}", total_size_under_100k);
-    println!("Part 2: {}", smallest_size);
 }
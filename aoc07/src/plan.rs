@@ -0,0 +1,245 @@
+//! Working out what to delete to make room for an update.
+
+use crate::fs::{FileSystem, NodeId};
+use std::collections::HashMap;
+
+/// The size of the disk, and how much of it needs to be free.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Disk {
+    pub capacity: u64,
+    pub needed: u64,
+}
+
+impl Disk {
+    /// The device in the puzzle, and the space its update needs.
+    pub const PUZZLE: Disk = Disk {
+        capacity: 70000000,
+        needed: 30000000,
+    };
+
+    /// How much more needs freeing on `fs`, which may be nothing.
+    pub fn to_free(&self, fs: &FileSystem) -> u64 {
+        let used = fs.node(fs.root()).size();
+        let free = self.capacity.saturating_sub(used);
+        self.needed.saturating_sub(free)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub to_free: u64,
+    /// Every directory that frees enough on its own, smallest first.
+    pub candidates: Vec<NodeId>,
+}
+
+impl Plan {
+    pub fn new(fs: &FileSystem, disk: Disk) -> Plan {
+        let to_free = disk.to_free(fs);
+        let mut candidates = fs
+            .directories()
+            .filter(|&dir| fs.node(dir).size() >= to_free)
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|&dir| fs.node(dir).size());
+        Plan {
+            to_free,
+            candidates,
+        }
+    }
+    /// The smallest directory that frees enough on its own.
+    pub fn smallest(&self) -> Option<NodeId> {
+        self.candidates.first().copied()
+    }
+    /// The set of directories, none inside another, that frees enough while deleting as little
+    /// as possible, or `None` if even deleting everything wouldn't do. See `Deletion::exact` for
+    /// when it might not be the very best.
+    pub fn best(&self, fs: &FileSystem) -> Option<Deletion> {
+        if self.to_free == 0 {
+            return Some(Deletion {
+                dirs: Vec::new(),
+                size: 0,
+                exact: true,
+            });
+        }
+        Planner::new(fs, self.to_free).run()
+    }
+}
+
+/// Directories to delete together, none inside another, in walk order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deletion {
+    pub dirs: Vec<NodeId>,
+    /// How much deleting them frees.
+    pub size: u64,
+    /// Whether this is known to free the least possible. It is unless what's inside some
+    /// directory can be picked to give more than 256 different totals short of enough, when
+    /// only an even spread of them is kept. Even then it's never worse than `Plan::smallest`,
+    /// and on real trees it's usually within a few bytes of the best.
+    pub exact: bool,
+}
+
+/// The most totals short of enough kept for any one directory.
+const MAX_TOTALS: usize = 256;
+
+/// A set of directories, none inside another, as a tree of the sets it was joined from, so sets
+/// can share their parts.
+enum Part {
+    Whole(NodeId),
+    Both(usize, usize),
+}
+
+/// A set of directories, by its total size and its part, or no part for the empty set.
+#[derive(Debug, Clone, Copy)]
+struct Pick {
+    total: u64,
+    part: Option<usize>,
+}
+
+/// The sets of directories that can be picked from within one directory: every distinct total
+/// that doesn't free enough, smallest first and starting with the empty set, and the smallest
+/// that does.
+struct Totals {
+    under: Vec<Pick>,
+    over: Option<Pick>,
+}
+
+/// A dynamic programme over the tree. Each directory can be deleted whole, or its subdirectories'
+/// choices combined; working up from the leaves, each directory's totals are built from its
+/// subdirectories', one at a time.
+struct Planner<'a> {
+    fs: &'a FileSystem,
+    to_free: u64,
+    parts: Vec<Part>,
+    exact: bool,
+}
+
+impl Planner<'_> {
+    fn new(fs: &FileSystem, to_free: u64) -> Planner<'_> {
+        Planner {
+            fs,
+            to_free,
+            parts: Vec::new(),
+            exact: true,
+        }
+    }
+
+    fn run(mut self) -> Option<Deletion> {
+        let fs = self.fs;
+        let dirs = fs.directories().collect::<Vec<_>>();
+        let mut totals = HashMap::new();
+        // Walk order reversed puts every directory after all of its subdirectories.
+        for &dir in dirs.iter().rev() {
+            let mut combined = Totals {
+                under: vec![Pick {
+                    total: 0,
+                    part: None,
+                }],
+                over: None,
+            };
+            for &child in fs.node(dir).children() {
+                if let Some(child) = totals.remove(&child) {
+                    combined = self.combine(combined, child);
+                }
+            }
+            // Nothing inside a directory can total more than the directory itself, so deleting
+            // it whole is either enough or a new largest total, unless it's no bigger than its
+            // subdirectories put together.
+            let size = fs.node(dir).size();
+            if size >= self.to_free || combined.under.last().unwrap().total < size {
+                let whole = Pick {
+                    total: size,
+                    part: Some(self.part(Part::Whole(dir))),
+                };
+                match size >= self.to_free {
+                    true => combined.over = Some(smaller(combined.over, whole)),
+                    false => combined.under.push(whole),
+                }
+            }
+            totals.insert(dir, combined);
+        }
+        let best = totals.remove(&fs.root()).unwrap().over?;
+        let order = dirs
+            .iter()
+            .enumerate()
+            .map(|(i, &dir)| (dir, i))
+            .collect::<HashMap<_, _>>();
+        let mut picked = self.dirs(best);
+        picked.sort_by_key(|dir| order[dir]);
+        Some(Deletion {
+            dirs: picked,
+            size: best.total,
+            exact: self.exact,
+        })
+    }
+
+    /// Every way of picking from both `a` and `b`, which are disjoint parts of the tree.
+    fn combine(&mut self, a: Totals, b: Totals) -> Totals {
+        // Adding anything to a set that already frees enough only makes it bigger.
+        let mut over = [a.over, b.over]
+            .into_iter()
+            .flatten()
+            .reduce(|x, y| smaller(Some(x), y));
+        let mut sums = Vec::with_capacity(a.under.len() * b.under.len());
+        for (i, x) in a.under.iter().enumerate() {
+            for (j, y) in b.under.iter().enumerate() {
+                sums.push((x.total + y.total, i, j));
+            }
+        }
+        sums.sort_unstable_by_key(|&(total, _, _)| total);
+        sums.dedup_by_key(|&mut (total, _, _)| total);
+        let split = sums.partition_point(|&(total, _, _)| total < self.to_free);
+        if let Some(&(_, i, j)) = sums.get(split) {
+            let pick = self.join(a.under[i], b.under[j]);
+            over = Some(smaller(over, pick));
+        }
+        sums.truncate(split);
+        if sums.len() > MAX_TOTALS {
+            // Keep an even spread, including the empty set and the largest total.
+            self.exact = false;
+            let last = sums.len() - 1;
+            sums = (0..MAX_TOTALS)
+                .map(|k| sums[k * last / (MAX_TOTALS - 1)])
+                .collect();
+        }
+        let under = sums
+            .into_iter()
+            .map(|(_, i, j)| self.join(a.under[i], b.under[j]))
+            .collect();
+        Totals { under, over }
+    }
+
+    fn join(&mut self, a: Pick, b: Pick) -> Pick {
+        let part = match (a.part, b.part) {
+            (None, part) | (part, None) => part,
+            (Some(x), Some(y)) => Some(self.part(Part::Both(x, y))),
+        };
+        Pick {
+            total: a.total + b.total,
+            part,
+        }
+    }
+
+    fn part(&mut self, part: Part) -> usize {
+        self.parts.push(part);
+        self.parts.len() - 1
+    }
+
+    /// The directories in `pick`, in no particular order.
+    fn dirs(&self, pick: Pick) -> Vec<NodeId> {
+        let mut dirs = Vec::new();
+        let mut stack = pick.part.into_iter().collect::<Vec<_>>();
+        while let Some(part) = stack.pop() {
+            match self.parts[part] {
+                Part::Whole(dir) => dirs.push(dir),
+                Part::Both(x, y) => stack.extend([x, y]),
+            }
+        }
+        dirs
+    }
+}
+
+fn smaller(a: Option<Pick>, b: Pick) -> Pick {
+    match a {
+        Some(a) if a.total <= b.total => a,
+        _ => b,
+    }
+}
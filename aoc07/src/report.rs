@@ -0,0 +1,106 @@
+//! Listings of a filesystem in the style of `tree` and `du -h`.
+
+use crate::fs::{FileSystem, Kind, NodeId};
+use std::fmt;
+
+/// The whole tree, one entry per line and indented by depth, in the puzzle's own format except
+/// that directories show their total size too:
+///
+/// ```text
+/// - / (dir, size=48381165)
+///   - a (dir, size=94853)
+///     - e (dir, size=584)
+///       - i (file, size=584)
+/// ```
+pub struct Tree<'a>(pub &'a FileSystem);
+
+impl fmt::Display for Tree<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fs = self.0;
+        for (id, depth) in fs.walk(fs.root()) {
+            let node = fs.node(id);
+            let name = match node.parent() {
+                None => "/",
+                Some(_) => node.name(),
+            };
+            let kind = match node.kind() {
+                Kind::Directory { .. } => "dir",
+                Kind::File => "file",
+            };
+            writeln!(
+                f,
+                "{}- {} ({}, size={})",
+                "  ".repeat(depth),
+                name,
+                kind,
+                node.size()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Every directory's total size and path, one per line, each directory after everything inside
+/// it, as `du` prints them. With `human` set, sizes are given like `du -h`.
+pub struct Du<'a> {
+    pub fs: &'a FileSystem,
+    pub human: bool,
+}
+
+impl fmt::Display for Du<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for dir in post_order(self.fs) {
+            let size = self.fs.node(dir).size();
+            let size = match self.human {
+                true => human_size(size),
+                false => size.to_string(),
+            };
+            writeln!(f, "{}\t{}", size, self.fs.path(dir))?;
+        }
+        Ok(())
+    }
+}
+
+/// The directories, each after all of its subdirectories, and siblings in the order they were
+/// added.
+fn post_order(fs: &FileSystem) -> Vec<NodeId> {
+    // A pre-order walk that visits children last to first, reversed.
+    let mut order = Vec::new();
+    let mut stack = vec![fs.root()];
+    while let Some(dir) = stack.pop() {
+        order.push(dir);
+        stack.extend(
+            fs.node(dir)
+                .children()
+                .iter()
+                .filter(|&&c| fs.node(c).is_dir()),
+        );
+    }
+    order.reverse();
+    order
+}
+
+/// A size the way `du -h` shows it: whole bytes below 1K, then in the smallest power of 1024
+/// that keeps it below 1024 once rounded up, with one decimal place below 10.
+pub fn human_size(size: u64) -> String {
+    const UNITS: [&str; 6] = ["K", "M", "G", "T", "P", "E"];
+    if size < 1024 {
+        return size.to_string();
+    }
+    let mut scaled = size as f64 / 1024.0;
+    for (i, unit) in UNITS.iter().enumerate() {
+        let rounded = match scaled < 10.0 {
+            true => (scaled * 10.0).ceil() / 10.0,
+            false => scaled.ceil(),
+        };
+        // Rounding up can reach 1024, e.g. 1023.9K, which is shown as 1.0M instead.
+        if rounded < 1024.0 || i + 1 == UNITS.len() {
+            return match rounded < 10.0 {
+                true => format!("{:.1}{}", rounded, unit),
+                false => format!("{}{}", rounded, unit),
+            };
+        }
+        scaled /= 1024.0;
+    }
+    unreachable!("the last unit is always used")
+}
@@ -0,0 +1,157 @@
+//! Replaying a terminal transcript onto a `FileSystem`, noting anything in it that doesn't add up.
+//!
+//! Some problems still leave a sensible filesystem behind: listing a directory twice, an entry
+//! repeated within one listing, `cd` into a directory no listing mentioned (it's created), `cd ..`
+//! at the root (it stays put), or listing output with no `$ ls` before it. Others don't: a file
+//! listed with two different sizes, or a name that's a file in one place and a directory in
+//! another, or files adding up to more than a `u64` can hold. `parse` accepts the first kind,
+//! while `validate` reports both.
+
+use crate::fs::{FileSystem, FsError, NodeId};
+use common::parse::{lines, Line, Token};
+use common::ParseError;
+use std::collections::HashMap;
+
+pub struct Problem {
+    pub error: ParseError,
+    /// Whether the transcript contradicts itself, rather than just being untidy.
+    pub fatal: bool,
+}
+
+#[derive(Default)]
+struct Replay {
+    fs: FileSystem,
+    /// The line each node was first listed on. The root is never listed.
+    listed_on: HashMap<NodeId, usize>,
+    /// The line of the `$ ls` for each directory listed so far.
+    ls_on: HashMap<NodeId, usize>,
+    /// The names in the listing being read, if any, and where each appeared in it.
+    listing: Option<HashMap<String, usize>>,
+    problems: Vec<Problem>,
+}
+
+impl Replay {
+    fn problem(&mut self, error: ParseError) {
+        self.problems.push(Problem {
+            error,
+            fatal: false,
+        });
+    }
+    fn fatal(&mut self, error: ParseError) {
+        self.problems.push(Problem { error, fatal: true });
+    }
+
+    fn line(&mut self, line: Line) -> Result<(), ParseError> {
+        let mut tokens = line.tokens();
+        let first = tokens.next_token("command or listing")?;
+        if first.text() == "$" {
+            self.listing = None;
+            let command = tokens.next_token("command")?;
+            match command.text() {
+                "cd" => self.cd(tokens.next_token("directory name")?),
+                "ls" => self.ls(line, command),
+                _ => return Err(command.error("`cd` or `ls`")),
+            }
+        } else if first.text() == "dir" {
+            let name = tokens.next_token("directory name")?;
+            self.entry(line, first, name, None);
+        } else {
+            let size = first.parse::<u64>("file size or `dir`")?;
+            let name = tokens.next_token("file name")?;
+            self.entry(line, first, name, Some(size));
+        }
+        tokens.end()
+    }
+
+    fn cd(&mut self, path: Token) {
+        let mut dir = match path.text().starts_with('/') {
+            true => self.fs.root(),
+            false => self.fs.cwd(),
+        };
+        for segment in path.split(&['/']) {
+            match segment.text() {
+                "" | "." => {}
+                ".." => match self.fs.node(dir).parent() {
+                    Some(parent) => dir = parent,
+                    None => self.problem(segment.error("a directory below the root to leave")),
+                },
+                name => match self.fs.child(dir, name) {
+                    Some(child) if self.fs.node(child).is_dir() => dir = child,
+                    Some(_) => {
+                        return self.fatal(segment.error("a directory, not a file"));
+                    }
+                    None => {
+                        self.problem(
+                            segment.error(format!("a directory listed in {}", self.fs.path(dir))),
+                        );
+                        dir = self.fs.mkdir(dir, name).unwrap();
+                    }
+                },
+            }
+        }
+        self.fs.set_cwd(dir).unwrap();
+    }
+
+    fn ls(&mut self, line: Line, command: Token) {
+        let cwd = self.fs.cwd();
+        match self.ls_on.get(&cwd) {
+            Some(&first) => self.problem(command.error(format!(
+                "each directory to be listed once ({} was listed on line {})",
+                self.fs.path(cwd),
+                first
+            ))),
+            None => {
+                self.ls_on.insert(cwd, line.number);
+            }
+        }
+        self.listing = Some(HashMap::new());
+    }
+
+    /// A line of `ls` output: a directory if `size` is `None`, otherwise a file.
+    fn entry(&mut self, line: Line, first: Token, name: Token, size: Option<u64>) {
+        match &mut self.listing {
+            None => self.problem(first.error("a command (listings follow `$ ls`)")),
+            Some(seen) => match seen.get(name.text()) {
+                Some(&earlier) => self.problem(name.error(format!(
+                    "each name once per listing (also on line {})",
+                    earlier
+                ))),
+                None => {
+                    seen.insert(name.text().to_string(), line.number);
+                }
+            },
+        }
+        let cwd = self.fs.cwd();
+        let added = match size {
+            None => self.fs.mkdir(cwd, name.text()),
+            Some(size) => self.fs.add_file(cwd, name.text(), size),
+        };
+        match added {
+            Ok(id) => {
+                self.listed_on.entry(id).or_insert(line.number);
+            }
+            Err(e @ FsError::TooLarge { .. }) => {
+                self.fatal(first.error(format!("a smaller file ({})", e)));
+            }
+            Err(e) => {
+                let existing = self.fs.child(cwd, name.text()).unwrap();
+                let token = if size.is_some() { first } else { name };
+                let first_listed = match self.listed_on.get(&existing) {
+                    Some(line) => format!("as on line {}", line),
+                    None => "as created by `cd`".to_string(),
+                };
+                self.fatal(token.error(format!("the same entry {} ({})", first_listed, e)));
+            }
+        }
+    }
+}
+
+/// Replays `input`, returning the filesystem it describes and every problem found along the way.
+/// Lines that can't be read at all are an error straight away.
+pub fn replay(input: &str) -> Result<(FileSystem, Vec<Problem>), ParseError> {
+    let mut replay = Replay::default();
+    for line in lines(input) {
+        replay.line(line)?;
+    }
+    Ok((replay.fs, replay.problems))
+}
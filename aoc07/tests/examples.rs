@@ -0,0 +1,23 @@
+use aoc07::{solve_part1, solve_part2, Day07};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day07-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day07>(EXAMPLE)), Answer::from(95437));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(
+        solve_part2(&parse::<Day07>(EXAMPLE)),
+        Answer::from(24933642)
+    );
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day07>();
+}
@@ -0,0 +1,121 @@
+use aoc07::{parse, FileSystem, FsError};
+
+const EXAMPLE: &str = include_str!("../../examples/day07-example.txt");
+
+#[test]
+fn sizes_are_cached() {
+    let fs = parse(EXAMPLE).unwrap();
+    let size = |path| fs.node(fs.resolve(path).unwrap()).size();
+    assert_eq!(size("/"), 48381165);
+    assert_eq!(size("/a"), 94853);
+    assert_eq!(size("/a/e"), 584);
+    assert_eq!(size("/d"), 24933642);
+    assert_eq!(size("/d/k"), 7214296);
+}
+
+#[test]
+fn walking_and_finding() {
+    let fs = parse(EXAMPLE).unwrap();
+    let walk = fs
+        .walk(fs.root())
+        .map(|(id, depth)| format!("{}{}", " ".repeat(depth), fs.path(id)))
+        .collect::<Vec<_>>();
+    assert_eq!(walk[..4], ["/", " /a", "  /a/e", "   /a/e/i"]);
+    assert_eq!(walk.len(), 14);
+    let dirs = fs.directories().map(|d| fs.path(d)).collect::<Vec<_>>();
+    assert_eq!(dirs, ["/", "/a", "/a/e", "/d"]);
+    let found = fs.find("i").map(|id| fs.path(id)).collect::<Vec<_>>();
+    assert_eq!(found, ["/a/e/i"]);
+    let e = fs.resolve("/a/e").unwrap();
+    assert_eq!(fs.node(e).parent(), fs.resolve("/a").ok());
+    assert_eq!(fs.node(fs.root()).parent(), None);
+}
+
+#[test]
+fn paths() {
+    let mut fs = FileSystem::new();
+    let deep = fs.mkdir_all("/x/y/z").unwrap();
+    assert_eq!(fs.path(deep), "/x/y/z");
+    fs.cd("x/y").unwrap();
+    assert_eq!(fs.path(fs.cwd()), "/x/y");
+    assert_eq!(fs.resolve("z/../../y/./z"), Ok(deep));
+    assert_eq!(fs.resolve("/../../x"), fs.resolve("/x"));
+    fs.add_file(fs.cwd(), "f", 10).unwrap();
+    assert_eq!(
+        fs.cd("f/.."),
+        Err(FsError::NotADirectory("/x/y/f".to_string()))
+    );
+    assert_eq!(
+        fs.cd("nope"),
+        Err(FsError::NotFound("/x/y/nope".to_string()))
+    );
+    assert_eq!(fs.path(fs.cwd()), "/x/y");
+}
+
+#[test]
+fn adding_files() {
+    let mut fs = FileSystem::new();
+    let dir = fs.mkdir(fs.root(), "a").unwrap();
+    fs.add_file(dir, "f", 5).unwrap();
+    // Listing the same file again doesn't count it twice.
+    fs.add_file(dir, "f", 5).unwrap();
+    assert_eq!(fs.node(fs.root()).size(), 5);
+    assert_eq!(
+        fs.add_file(dir, "f", 6),
+        Err(FsError::SizeConflict {
+            path: "/a/f".to_string(),
+            old: 5,
+            new: 6
+        })
+    );
+    assert_eq!(
+        fs.add_file(fs.root(), "a", 1),
+        Err(FsError::IsADirectory("/a".to_string()))
+    );
+    assert_eq!(
+        fs.mkdir(dir, "f"),
+        Err(FsError::NotADirectory("/a/f".to_string()))
+    );
+    // Listing a directory again keeps what's in it.
+    assert_eq!(fs.mkdir(fs.root(), "a"), Ok(dir));
+    assert_eq!(fs.node(dir).children().len(), 1);
+}
+
+#[test]
+fn huge_sizes() {
+    let fs = parse("$ cd /\n$ ls\n10000000000 big\n10000000000 bigger\n").unwrap();
+    assert_eq!(fs.node(fs.root()).size(), 20000000000);
+}
+
+#[test]
+fn wide_directories() {
+    let mut transcript = String::from("$ cd /\n$ ls\n");
+    for i in 0..200_000 {
+        transcript.push_str(&format!("{} f{}\ndir d{}\n", i + 1, i, i));
+    }
+    let fs = parse(&transcript).unwrap();
+    let root = fs.root();
+    assert_eq!(fs.node(root).children().len(), 400_000);
+    assert_eq!(fs.node(root).size(), 200_000 * 200_001 / 2);
+    let file = fs.child(root, "f199999").unwrap();
+    assert_eq!(fs.node(file).size(), 200_000);
+    assert!(fs.node(fs.child(root, "d123").unwrap()).is_dir());
+    assert_eq!(fs.child(file, "anything"), None);
+}
+
+#[test]
+fn totals_never_overflow() {
+    let mut fs = FileSystem::new();
+    let a = fs.mkdir(fs.root(), "a").unwrap();
+    fs.add_file(a, "big", u64::MAX - 1).unwrap();
+    assert_eq!(
+        fs.add_file(fs.root(), "x", 2),
+        Err(FsError::TooLarge {
+            path: "/x".to_string(),
+            size: 2
+        })
+    );
+    assert_eq!(fs.child(fs.root(), "x"), None);
+    fs.add_file(fs.root(), "y", 1).unwrap();
+    assert_eq!(fs.node(fs.root()).size(), u64::MAX);
+}
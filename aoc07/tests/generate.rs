@@ -0,0 +1,148 @@
+use aoc07::generate::{from_disk, Transcript};
+use aoc07::{parse, validate, FileSystem, NodeId};
+use std::path::PathBuf;
+
+const EXAMPLE: &str = include_str!("../../examples/day07-example.txt");
+
+/// Every node as (depth, name, is a directory, size), in walk order.
+fn listing(fs: &FileSystem) -> Vec<(usize, String, bool, u64)> {
+    fs.walk(fs.root())
+        .map(|(id, depth)| {
+            let node = fs.node(id);
+            (depth, node.name().to_string(), node.is_dir(), node.size())
+        })
+        .collect()
+}
+
+/// Writes `fs` out as a transcript and reads it back, which should be spotless and give the
+/// same tree.
+fn round_trip(fs: &FileSystem) -> FileSystem {
+    let transcript = Transcript(fs).to_string();
+    let again = match validate(&transcript) {
+        Ok(again) => again,
+        Err(problems) => panic!("{}", problems[0].pretty("transcript")),
+    };
+    assert_eq!(listing(&again), listing(fs));
+    again
+}
+
+#[test]
+fn example_round_trips() {
+    let fs = parse(EXAMPLE).unwrap();
+    let again = round_trip(&fs);
+    assert_eq!(Transcript(&again).to_string(), Transcript(&fs).to_string());
+    assert_eq!(aoc07::solve_part1(&again), aoc07::solve_part1(&fs));
+    assert_eq!(aoc07::solve_part2(&again), aoc07::solve_part2(&fs));
+}
+
+#[test]
+fn transcript_format() {
+    let mut fs = FileSystem::new();
+    let a = fs.mkdir(fs.root(), "a").unwrap();
+    fs.add_file(fs.root(), "b.txt", 10).unwrap();
+    fs.mkdir(a, "empty").unwrap();
+    fs.add_file(a, "c", 5).unwrap();
+    assert_eq!(
+        Transcript(&fs).to_string(),
+        "$ cd /\n$ ls\ndir a\n10 b.txt\n\
+         $ cd a\n$ ls\ndir empty\n5 c\n\
+         $ cd empty\n$ ls\n$ cd ..\n$ cd ..\n"
+    );
+}
+
+#[test]
+fn deep_nesting() {
+    let mut fs = FileSystem::new();
+    let mut dir = fs.root();
+    for depth in 0..5000 {
+        fs.add_file(dir, &format!("f{}", depth), depth).unwrap();
+        dir = fs.mkdir(dir, "d").unwrap();
+    }
+    let again = round_trip(&fs);
+    assert_eq!(again.node(again.root()).size(), 4999 * 5000 / 2);
+}
+
+#[test]
+fn huge_sizes() {
+    let mut fs = FileSystem::new();
+    let big = u64::MAX / 4;
+    let a = fs.mkdir(fs.root(), "a").unwrap();
+    fs.add_file(a, "x", big).unwrap();
+    fs.add_file(a, "y", big).unwrap();
+    fs.add_file(fs.root(), "z", big + 1).unwrap();
+    fs.add_file(fs.root(), "0", 0).unwrap();
+    let again = round_trip(&fs);
+    assert_eq!(again.node(again.root()).size(), 3 * big + 1);
+}
+
+#[test]
+fn wide_trees() {
+    let mut fs = FileSystem::new();
+    let dirs = (0..300)
+        .map(|i| fs.mkdir(fs.root(), &format!("d{}", i)).unwrap())
+        .collect::<Vec<NodeId>>();
+    for (i, &dir) in dirs.iter().enumerate() {
+        for j in 0..20 {
+            fs.add_file(dir, &format!("f{}", j), (i * j) as u64)
+                .unwrap();
+        }
+        fs.mkdir(dir, "dir").unwrap();
+    }
+    round_trip(&fs);
+}
+
+/// A fresh scratch directory, removed again when dropped.
+struct Scratch(PathBuf);
+
+impl Scratch {
+    fn new(name: &str) -> Scratch {
+        let path = std::env::temp_dir().join(format!("aoc07-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        Scratch(path)
+    }
+    fn file(&self, path: &str, size: usize) {
+        let path = self.0.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, vec![b'x'; size]).unwrap();
+    }
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn reads_real_directories() {
+    let scratch = Scratch::new("disk");
+    scratch.file("top.txt", 100);
+    scratch.file("b/inner", 20);
+    scratch.file("a/deep/er/file", 7);
+    scratch.file("a/two words", 1000);
+    std::fs::create_dir_all(scratch.0.join("a/empty")).unwrap();
+
+    let fs = from_disk(&scratch.0).unwrap();
+    let size = |path| fs.node(fs.resolve(path).unwrap()).size();
+    assert_eq!(size("/"), 127);
+    assert_eq!(size("/a"), 7);
+    assert_eq!(size("/a/deep/er/file"), 7);
+    assert_eq!(size("/b"), 20);
+    assert!(fs.resolve("/a/empty").is_ok());
+    assert!(fs.find("words").next().is_none());
+    let names = fs
+        .node(fs.root())
+        .children()
+        .iter()
+        .map(|&c| fs.node(c).name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["a", "b", "top.txt"]);
+    round_trip(&fs);
+}
+
+#[test]
+fn missing_directory() {
+    let scratch = Scratch::new("missing");
+    assert!(from_disk(&scratch.0.join("nope")).is_err());
+}
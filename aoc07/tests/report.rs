@@ -0,0 +1,184 @@
+use aoc07::report::{human_size, Du, Tree};
+use aoc07::{parse, Disk, FileSystem, Plan};
+use common::testing::Rng;
+
+const EXAMPLE: &str = include_str!("../../examples/day07-example.txt");
+
+fn paths(fs: &FileSystem, dirs: &[aoc07::NodeId]) -> Vec<String> {
+    dirs.iter().map(|&dir| fs.path(dir)).collect()
+}
+
+#[test]
+fn tree() {
+    let fs = parse(EXAMPLE).unwrap();
+    let tree = Tree(&fs).to_string();
+    let lines = tree.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 14);
+    assert_eq!(lines[0], "- / (dir, size=48381165)");
+    assert_eq!(lines[1], "  - a (dir, size=94853)");
+    assert_eq!(lines[3], "      - i (file, size=584)");
+    assert_eq!(lines[13], "    - k (file, size=7214296)");
+}
+
+#[test]
+fn du() {
+    let fs = parse(EXAMPLE).unwrap();
+    let du = Du {
+        fs: &fs,
+        human: false,
+    };
+    assert_eq!(
+        du.to_string(),
+        "584\t/a/e\n94853\t/a\n24933642\t/d\n48381165\t/\n"
+    );
+    let du = Du {
+        fs: &fs,
+        human: true,
+    };
+    assert_eq!(du.to_string(), "584\t/a/e\n93K\t/a\n24M\t/d\n47M\t/\n");
+}
+
+#[test]
+fn human_sizes() {
+    assert_eq!(human_size(0), "0");
+    assert_eq!(human_size(1023), "1023");
+    assert_eq!(human_size(1024), "1.0K");
+    assert_eq!(human_size(1025), "1.1K");
+    assert_eq!(human_size(10 * 1024), "10K");
+    assert_eq!(human_size(10 * 1024 + 1), "11K");
+    assert_eq!(human_size(1024 * 1024), "1.0M");
+    assert_eq!(human_size(1024 * 1024 - 1), "1.0M");
+    assert_eq!(human_size(1023 * 1024 + 1), "1.0M");
+    assert_eq!(human_size(1023 * 1024), "1023K");
+    assert_eq!(human_size(10 * 1024 - 1), "10K");
+    assert_eq!(human_size(u64::MAX), "16E");
+}
+
+#[test]
+fn puzzle_plan() {
+    let fs = parse(EXAMPLE).unwrap();
+    let plan = Plan::new(&fs, Disk::PUZZLE);
+    assert_eq!(plan.to_free, 8381165);
+    assert_eq!(paths(&fs, &plan.candidates), ["/d", "/"]);
+    assert_eq!(fs.path(plan.smallest().unwrap()), "/d");
+    assert_eq!(paths(&fs, &plan.best(&fs).unwrap().dirs), ["/d"]);
+}
+
+#[test]
+fn combined_plan_beats_any_single_directory() {
+    let transcript = "$ cd /\n$ ls\ndir x\ndir y\ndir z\n\
+                      $ cd x\n$ ls\n60 f\ndir w\n$ cd w\n$ ls\n45 f\n\
+                      $ cd /y\n$ ls\n50 f\n$ cd /z\n$ ls\n30 f\n";
+    let fs = parse(transcript).unwrap();
+    let disk = Disk {
+        capacity: 205,
+        needed: 150,
+    };
+    let plan = Plan::new(&fs, disk);
+    assert_eq!(plan.to_free, 130);
+    assert_eq!(paths(&fs, &plan.candidates), ["/"]);
+    // /x/w, /y and /z together come to 125, just short.
+    assert_eq!(paths(&fs, &plan.best(&fs).unwrap().dirs), ["/x", "/z"]);
+
+    let plan = Plan::new(
+        &fs,
+        Disk {
+            capacity: 205,
+            needed: 1000,
+        },
+    );
+    assert!(plan.candidates.is_empty());
+    assert_eq!(plan.best(&fs), None);
+    let plan = Plan::new(
+        &fs,
+        Disk {
+            capacity: 1000,
+            needed: 100,
+        },
+    );
+    assert_eq!(plan.to_free, 0);
+    let best = plan.best(&fs).unwrap();
+    assert_eq!((best.dirs.len(), best.size, best.exact), (0, 0, true));
+}
+
+/// A tree of `dirs` directories, each under a random earlier one, with files spread among them.
+fn random_tree(dirs: usize, files: usize, seed: u64) -> FileSystem {
+    let mut rng = Rng::new(seed);
+    let mut fs = FileSystem::new();
+    let mut all = vec![fs.root()];
+    for i in 0..dirs {
+        let parent = all[rng.below(all.len())];
+        all.push(fs.mkdir(parent, &format!("d{}", i)).unwrap());
+    }
+    for i in 0..files {
+        let parent = all[rng.below(all.len())];
+        let size = 1000 + rng.below(300_000) as u64;
+        fs.add_file(parent, &format!("f{}", i), size).unwrap();
+    }
+    fs
+}
+
+/// The least that deleting directories, none inside another, can free while freeing at least
+/// `to_free`, trying every set.
+fn brute_force(fs: &FileSystem, to_free: u64) -> Option<u64> {
+    let dirs = fs.directories().map(|d| fs.path(d)).collect::<Vec<_>>();
+    let inside = |a: &str, b: &str| b == "/" || a.starts_with(&format!("{}/", b));
+    (0..1u32 << dirs.len())
+        .filter_map(|set| {
+            let picked = (0..dirs.len()).filter(|i| set >> i & 1 == 1);
+            let picked = picked.map(|i| &dirs[i]).collect::<Vec<_>>();
+            let nested = picked
+                .iter()
+                .any(|a| picked.iter().any(|b| a != b && inside(a, b)));
+            let total = picked
+                .iter()
+                .map(|p| fs.node(fs.resolve(p).unwrap()).size())
+                .sum::<u64>();
+            (!nested && total >= to_free).then_some(total)
+        })
+        .min()
+}
+
+#[test]
+fn best_plans_are_the_least_possible() {
+    for seed in 1..=20 {
+        // Few enough directories that no set of totals needs thinning out.
+        let fs = random_tree(7, 24, seed);
+        let used = fs.node(fs.root()).size();
+        for needed in [used / 10, used / 3, used / 2 + 1] {
+            let plan = Plan::new(
+                &fs,
+                Disk {
+                    capacity: used,
+                    needed,
+                },
+            );
+            let best = plan.best(&fs);
+            assert_eq!(best.as_ref().map(|b| b.size), brute_force(&fs, needed));
+            let best = best.unwrap();
+            assert!(best.exact);
+            let size = best.dirs.iter().map(|&d| fs.node(d).size()).sum::<u64>();
+            assert_eq!(size, best.size);
+        }
+    }
+}
+
+#[test]
+fn planning_for_thousands_of_directories() {
+    let fs = random_tree(3000, 12000, 7);
+    let used = fs.node(fs.root()).size();
+    for needed in [used / 100, used / 7, used / 3 + 7, used - 1] {
+        let plan = Plan::new(
+            &fs,
+            Disk {
+                capacity: used,
+                needed,
+            },
+        );
+        let best = plan.best(&fs).unwrap();
+        assert!(best.size >= needed);
+        assert!(best.size <= fs.node(plan.smallest().unwrap()).size());
+        let size = best.dirs.iter().map(|&d| fs.node(d).size()).sum::<u64>();
+        assert_eq!(size, best.size);
+    }
+}
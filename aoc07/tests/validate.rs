@@ -0,0 +1,110 @@
+use aoc07::{parse, validate, FileSystem};
+
+const EXAMPLE: &str = include_str!("../../examples/day07-example.txt");
+
+/// Each problem `validate` finds, as `line: expected`.
+fn problems(transcript: &str) -> Vec<String> {
+    match validate(transcript) {
+        Ok(_) => Vec::new(),
+        Err(errors) => errors
+            .iter()
+            .map(|e| format!("{}: {}", e.location.as_ref().unwrap().line, e.expected))
+            .collect(),
+    }
+}
+
+fn size(fs: &FileSystem, path: &str) -> u64 {
+    fs.node(fs.resolve(path).unwrap()).size()
+}
+
+#[test]
+fn example_is_clean() {
+    assert!(validate(EXAMPLE).is_ok());
+}
+
+#[test]
+fn repeated_listings() {
+    let transcript = "$ cd /\n$ ls\n5 f\ndir a\n$ ls\n5 f\ndir a\n";
+    assert_eq!(
+        problems(transcript),
+        ["5: each directory to be listed once (/ was listed on line 2)"]
+    );
+    // Listing again neither empties the directory nor counts its files twice.
+    let fs = parse(transcript).unwrap();
+    assert_eq!(fs.node(fs.root()).size(), 5);
+    assert_eq!(fs.node(fs.root()).children().len(), 2);
+}
+
+#[test]
+fn repeated_entries() {
+    let transcript = "$ ls\n5 f\n5 f\n";
+    assert_eq!(
+        problems(transcript),
+        ["3: each name once per listing (also on line 2)"]
+    );
+    assert_eq!(size(&parse(transcript).unwrap(), "/"), 5);
+}
+
+#[test]
+fn contradictions() {
+    let transcript = "$ ls\n5 f\ndir a\n$ cd a\n$ cd ..\n$ ls\n6 f\n7 a\n";
+    assert_eq!(
+        problems(transcript),
+        [
+            "6: each directory to be listed once (/ was listed on line 1)",
+            "7: the same entry as on line 2 (/f has size 5, not 6)",
+            "8: the same entry as on line 3 (/a is a directory, not a file)",
+        ]
+    );
+    let e = parse(transcript).unwrap_err();
+    assert_eq!(e.location.unwrap().line, 7);
+    assert_eq!(e.found.as_deref(), Some("6"));
+}
+
+#[test]
+fn unknown_directories_and_leaving_the_root() {
+    let transcript = "$ cd /\n$ ls\ndir a\n$ cd a/b\n$ ls\n1 x\n$ cd /../..\n$ cd a/b/x\n";
+    assert_eq!(
+        problems(transcript),
+        [
+            "4: a directory listed in /a",
+            "7: a directory below the root to leave",
+            "7: a directory below the root to leave",
+            "8: a directory, not a file",
+        ]
+    );
+    let e = validate(transcript).unwrap_err();
+    assert_eq!(e[0].found.as_deref(), Some("b"));
+    assert_eq!(e[0].location.as_ref().unwrap().column, 8);
+    // Without validation, `cd` creates what it needs and stops at the root, but can't go into a
+    // file.
+    assert_eq!(parse(transcript).unwrap_err().location.unwrap().line, 8);
+    let fs = parse("$ cd a/b\n$ ls\n1 x\n$ cd /../..\n$ ls\n2 y\n").unwrap();
+    assert_eq!(size(&fs, "/a/b"), 1);
+    assert_eq!(size(&fs, "/"), 3);
+}
+
+#[test]
+fn listing_without_ls() {
+    assert_eq!(
+        problems("$ cd /\n5 f\n"),
+        ["2: a command (listings follow `$ ls`)"]
+    );
+}
+
+#[test]
+fn unreadable_lines() {
+    let e = validate("$ ls\nfive f\n").unwrap_err();
+    assert_eq!(e.len(), 1);
+    assert_eq!(e[0].expected, "file size or `dir`");
+}
+
+#[test]
+fn sizes_past_u64() {
+    let transcript = "$ cd /\n$ ls\n18446744073709551615 a\ndir d\n$ cd d\n$ ls\n1 b\n";
+    assert_eq!(
+        problems(transcript),
+        ["7: a smaller file (adding /d/b of size 1 would take the total past 18446744073709551615)"]
+    );
+    assert!(parse(transcript).is_err());
+}
@@ -0,0 +1,124 @@
+use common::grid::{Grid, Point};
+use common::{Answer, ParseError, Solution};
+
+/// A grid of tree heights. Everything about what each tree can see comes from sweeping along
+/// every row and column in both directions with a monotonic stack, so it all takes linear time
+/// and no recursion, however big the forest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forest {
+    heights: Grid<u8>,
+}
+
+impl Forest {
+    pub fn new(heights: Grid<u8>) -> Forest {
+        Forest { heights }
+    }
+    pub fn heights(&self) -> &Grid<u8> {
+        &self.heights
+    }
+
+    /// Whether each tree can be seen from outside the forest, looking along a row or column.
+    pub fn visibility(&self) -> Grid<bool> {
+        let mut visible = self.heights.map(|_, _| false);
+        self.sweep(|p, view| visible[p] |= view.to_edge);
+        visible
+    }
+    /// Each tree's scenic score: the product of its viewing distances in all four directions.
+    pub fn scenic_scores(&self) -> Grid<u64> {
+        let mut scores = self.heights.map(|_, _| 1);
+        self.sweep(|p, view| scores[p] *= view.distance as u64);
+        scores
+    }
+
+    /// Calls `f` with what each tree sees looking in each of the four directions.
+    ///
+    /// Each row or column is walked away from the edge being looked towards, keeping a stack of
+    /// the trees passed so far that nothing since has hidden, so their heights decrease towards
+    /// the top. Popping everything shorter than the next tree leaves the nearest tree at least as
+    /// tall on top, which is where its view stops; an empty stack means it sees the edge. Each
+    /// tree is pushed and popped once per direction.
+    fn sweep(&self, mut f: impl FnMut(Point, View)) {
+        let (start, end) = (self.heights.origin(), self.heights.end());
+        let (width, height) = (self.heights.width(), self.heights.height());
+        let rows = (start.y..=end.y).flat_map(|y| {
+            [
+                (Point::new(start.x, y), Point::RIGHT, width),
+                (Point::new(end.x, y), Point::LEFT, width),
+            ]
+        });
+        let columns = (start.x..=end.x).flat_map(|x| {
+            [
+                (Point::new(x, start.y), Point::DOWN, height),
+                (Point::new(x, end.y), Point::UP, height),
+            ]
+        });
+        let mut stack: Vec<(usize, u8)> = Vec::new();
+        for (first, step, len) in rows.chain(columns) {
+            stack.clear();
+            let mut p = first;
+            for i in 0..len {
+                let height = self.heights[p];
+                while stack.last().is_some_and(|&(_, h)| h < height) {
+                    stack.pop();
+                }
+                let view = match stack.last() {
+                    Some(&(blocker, _)) => View {
+                        distance: i - blocker,
+                        to_edge: false,
+                    },
+                    None => View {
+                        distance: i,
+                        to_edge: true,
+                    },
+                };
+                f(p, view);
+                stack.push((i, height));
+                p += step;
+            }
+        }
+    }
+}
+
+/// What a tree sees looking in one direction.
+#[derive(Debug, Clone, Copy)]
+struct View {
+    /// How many trees it can see, up to and including the first that's at least as tall.
+    distance: usize,
+    /// Whether no tree that tall is in the way, so it can be seen from the edge.
+    to_edge: bool,
+}
+
+pub type Input = Forest;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let heights = Grid::parse(input, "tree height (0-9)", |c| {
+        c.to_digit(10).map(|d| d as u8)
+    })?;
+    Ok(Forest::new(heights))
+}
+
+pub fn solve_part1(forest: &Input) -> Answer {
+    let visible = forest.visibility();
+    visible.iter().filter(|&(_, &v)| v).count().into()
+}
+
+pub fn solve_part2(forest: &Input) -> Answer {
+    let scores = forest.scenic_scores();
+    scores.iter().map(|(_, &s)| s).max().unwrap_or(0).into()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
@@ -0,0 +1,20 @@
+use aoc08::{solve_part1, solve_part2, Day08};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day08-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day08>(EXAMPLE)), Answer::from(21));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day08>(EXAMPLE)), Answer::from(8));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day08>();
+}
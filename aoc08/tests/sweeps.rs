@@ -0,0 +1,86 @@
+use aoc08::{parse, solve_part1, solve_part2, Forest};
+use common::grid::{Grid, Point};
+use common::testing::Rng;
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day08-example.txt");
+
+/// What the tree at `p` sees looking along `step`, walking outward one tree at a time: how far,
+/// and whether all the way to the edge.
+fn look(heights: &Grid<u8>, p: Point, step: Point) -> (u64, bool) {
+    let mut distance = 0;
+    let mut q = p + step;
+    while let Some(&h) = heights.get(q) {
+        distance += 1;
+        if h >= heights[p] {
+            return (distance, false);
+        }
+        q += step;
+    }
+    (distance, true)
+}
+
+fn random_forest(width: usize, height: usize, seed: u64) -> Forest {
+    let mut rng = Rng::new(seed);
+    let mut heights = Grid::new(width, height, 0);
+    for p in heights.points() {
+        heights[p] = rng.below(10) as u8;
+    }
+    Forest::new(heights)
+}
+
+#[test]
+fn example_grids() {
+    let forest = parse(EXAMPLE).unwrap();
+    let visible = forest.visibility();
+    let shown = visible.render(|_, &v| if v { '#' } else { '.' });
+    assert_eq!(shown, "#####\n###.#\n##.##\n#.#.#\n#####\n");
+    let scores = forest.scenic_scores();
+    assert_eq!(scores[Point::new(2, 1)], 4);
+    assert_eq!(scores[Point::new(2, 3)], 8);
+    assert_eq!(scores[Point::new(0, 0)], 0);
+}
+
+#[test]
+fn matches_walking_outward() {
+    for (seed, (width, height)) in [(1, 1), (1, 7), (9, 1), (6, 6), (23, 17), (40, 3)]
+        .into_iter()
+        .enumerate()
+    {
+        let forest = random_forest(width, height, seed as u64 + 1);
+        let heights = forest.heights();
+        let visible = forest.visibility();
+        let scores = forest.scenic_scores();
+        for p in heights.points() {
+            let views = Point::DIRECTIONS4.map(|d| look(heights, p, d));
+            assert_eq!(visible[p], views.iter().any(|v| v.1), "visibility at {}", p);
+            let score = views.iter().map(|v| v.0).product::<u64>();
+            assert_eq!(scores[p], score, "score at {}", p);
+        }
+    }
+}
+
+#[test]
+fn big_forests() {
+    // Trees all the same height only see their neighbours, so only the edge is visible.
+    let size = 3000;
+    let forest = Forest::new(Grid::new(size, size, 5));
+    assert_eq!(solve_part1(&forest), Answer::from(4 * size - 4));
+    assert_eq!(solve_part2(&forest), Answer::from(1));
+    // A lone tall tree in the middle sees all the way to every edge.
+    let mut heights = Grid::new(size, size, 3);
+    heights[Point::new(1000, 2000)] = 9;
+    let forest = Forest::new(heights);
+    assert_eq!(solve_part1(&forest), Answer::from(4 * size - 4 + 1));
+    assert_eq!(
+        solve_part2(&forest),
+        Answer::from(1000u64 * 1999 * 2000 * 999)
+    );
+}
+
+#[test]
+fn tall_and_thin() {
+    let forest = random_forest(1, 200_000, 7);
+    assert_eq!(solve_part1(&forest), Answer::from(200_000));
+    assert_eq!(solve_part2(&forest), Answer::from(0));
+}
@@ -0,0 +1,110 @@
+use common::parse::lines;
+use common::{Answer, ParseError, Solution};
+use log::{debug, trace};
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[rustfmt::skip]
+const MOVES: [[(i32, i32); 5]; 5] = [
+    [( 1, 1), ( 1, 1), ( 1, 0), ( 1,-1), ( 1,-1)],
+    [( 1, 1), ( 0, 0), ( 0, 0), ( 0, 0), ( 1,-1)],
+    [( 0, 1), ( 0, 0), ( 0, 0), ( 0, 0), ( 0,-1)],
+    [(-1, 1), ( 0, 0), ( 0, 0), ( 0, 0), (-1,-1)],
+    [(-1, 1), (-1, 1), (-1, 0), (-1,-1), (-1,-1)],
+];
+
+fn move_tail(h: (i32, i32), t: (i32, i32)) -> (i32, i32) {
+    trace!("move_tail({:?}, {:?})", h, t);
+    let delta = MOVES[(h.0 - t.0 + 2) as usize][(h.1 - t.1 + 2) as usize];
+    (t.0 - delta.0, t.1 - delta.1)
+}
+
+/// Head motions: a direction and a number of steps.
+pub type Input = Vec<(Direction, usize)>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut directions = Vec::new();
+    for line in lines(input) {
+        let mut tokens = line.tokens();
+        let direction = tokens.next_token("direction")?;
+        let d = match direction.text() {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(direction.error("direction (U, D, L or R)")),
+        };
+        directions.push((d, tokens.parse_next::<usize>("step count")?));
+        tokens.end()?;
+    }
+    Ok(directions)
+}
+
+pub fn solve_part1(directions: &Input) -> Answer {
+    let mut seen = HashSet::new();
+    let mut h = (0, 0);
+    let mut t = (0, 0);
+    trace!("Head: {:?}, Tail: {:?}", h, t);
+    seen.insert(t);
+    for (d, count) in directions {
+        for _ in 0..*count {
+            h = match d {
+                Direction::Up => (h.0 - 1, h.1),
+                Direction::Down => (h.0 + 1, h.1),
+                Direction::Left => (h.0, h.1 - 1),
+                Direction::Right => (h.0, h.1 + 1),
+            };
+            t = move_tail(h, t);
+            trace!("Moved {:?}. Head: {:?}, Tail: {:?}", d, h, t);
+            seen.insert(t);
+        }
+    }
+    seen.len().into()
+}
+
+pub fn solve_part2(directions: &Input) -> Answer {
+    let mut seen = HashSet::new();
+    let mut rope = [(0, 0); 10];
+    debug!("Rope: {:?}", rope);
+    seen.insert(rope[9]);
+    for (d, count) in directions {
+        for _ in 0..*count {
+            trace!("Move {:?}", d);
+            rope[0] = match d {
+                Direction::Up => (rope[0].0 - 1, rope[0].1),
+                Direction::Down => (rope[0].0 + 1, rope[0].1),
+                Direction::Left => (rope[0].0, rope[0].1 - 1),
+                Direction::Right => (rope[0].0, rope[0].1 + 1),
+            };
+            for i in 1..rope.len() {
+                rope[i] = move_tail(rope[i - 1], rope[i]);
+            }
+            trace!("  Rope: {:?}", rope);
+            seen.insert(rope[9]);
+        }
+    }
+    seen.len().into()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
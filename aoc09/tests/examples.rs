@@ -0,0 +1,29 @@
+use aoc09::{solve_part1, solve_part2, Day09};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day09-example.txt");
+const LARGER_EXAMPLE: &str = include_str!("../../examples/day09-larger.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day09>(EXAMPLE)), Answer::from(13));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day09>(EXAMPLE)), Answer::from(1));
+}
+
+#[test]
+fn part2_larger_example() {
+    assert_eq!(
+        solve_part2(&parse::<Day09>(LARGER_EXAMPLE)),
+        Answer::from(36)
+    );
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day09>();
+}
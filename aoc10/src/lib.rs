@@ -0,0 +1,86 @@
+use common::parse::lines;
+use common::{Answer, ParseError, Solution};
+use log::{debug, trace};
+use std::collections::HashSet;
+
+pub enum Op {
+    Noop,
+    Addx(i32),
+}
+
+/// The program, with each `addx` preceded by a `noop` so that every op takes one cycle.
+pub type Input = Vec<Op>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut program = Vec::new();
+    for line in lines(input) {
+        let mut tokens = line.tokens();
+        let instruction = tokens.next_token("instruction")?;
+        if instruction.text() == "noop" {
+            program.push(Op::Noop);
+        } else if instruction.text() == "addx" {
+            program.push(Op::Noop);
+            program.push(Op::Addx(tokens.parse_next::<i32>("integer")?));
+        } else {
+            return Err(instruction.error("`noop` or `addx`"));
+        }
+        tokens.end()?;
+    }
+    Ok(program)
+}
+
+pub fn solve_part1(program: &Input) -> Answer {
+    let interesting_cycles = HashSet::from([20, 60, 100, 140, 180, 220]);
+    let mut ss = 0;
+    let mut x = 1;
+    for (pc, op) in (1..).zip(program) {
+        if interesting_cycles.contains(&pc) {
+            debug!("Cycle {}, x = {}, product = {}", pc, x, pc * x);
+            ss += pc * x;
+        }
+        if let Op::Addx(operand) = op {
+            x += operand;
+        }
+    }
+    ss.into()
+}
+
+pub fn solve_part2(program: &Input) -> Answer {
+    let w = 40;
+    let mut crt = Vec::new();
+    let mut crt_line = String::new();
+    let mut x = 1;
+    for (pc, op) in (1..).zip(program) {
+        let pixel = (pc - 1) % w;
+        crt_line.push(if pixel == x || pixel == x - 1 || pixel == x + 1 {
+            '#'
+        } else {
+            '.'
+        });
+        trace!("{}", crt_line);
+        if crt_line.len() == w as usize {
+            crt.push(crt_line);
+            crt_line = String::from("");
+        }
+        if let Op::Addx(operand) = op {
+            x += operand;
+        }
+    }
+    format!("\n{}", crt.join("\n")).into()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
@@ -0,0 +1,31 @@
+use aoc10::{solve_part1, solve_part2, Day10};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day10-example.txt");
+
+const EXAMPLE_SCREEN: &str = "
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day10>(EXAMPLE)), Answer::from(13140));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(
+        solve_part2(&parse::<Day10>(EXAMPLE)),
+        Answer::from(EXAMPLE_SCREEN.to_string())
+    );
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day10>();
+}
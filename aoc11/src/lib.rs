@@ -0,0 +1,245 @@
+use common::parse::{lines, Token};
+use common::{Answer, ParseError, Solution};
+use log::trace;
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Old,
+    Literal(i64),
+}
+impl Operand {
+    fn parse(token: Token) -> Result<Operand, ParseError> {
+        if token.text() == "old" {
+            Ok(Operand::Old)
+        } else {
+            Ok(Operand::Literal(token.parse::<i64>("`old` or integer")?))
+        }
+    }
+    fn value(&self, old: i64) -> i64 {
+        match *self {
+            Operand::Old => old,
+            Operand::Literal(val) => val,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Operator {
+    Add,
+    Multiply,
+}
+impl Operator {
+    fn parse(token: Token) -> Result<Operator, ParseError> {
+        match token.text() {
+            "*" => Ok(Operator::Multiply),
+            "+" => Ok(Operator::Add),
+            _ => Err(token.error("`+` or `*`")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Operation {
+    op1: Operand,
+    operator: Operator,
+    op2: Operand,
+}
+impl Operation {
+    fn apply(&self, worry: i64) -> i64 {
+        let v1 = self.op1.value(worry);
+        let v2 = self.op2.value(worry);
+        match self.operator {
+            Operator::Add => v1 + v2,
+            Operator::Multiply => v1 * v2,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<i64>,
+    operation: Operation,
+    test: i64,
+    if_true: usize,
+    if_false: usize,
+    inspections: i64,
+}
+
+impl Monkey {
+    fn new() -> Monkey {
+        Monkey {
+            items: Vec::new(),
+            operation: Operation {
+                op1: Operand::Old,
+                operator: Operator::Add,
+                op2: Operand::Old,
+            },
+            test: 0,
+            if_true: 0,
+            if_false: 0,
+            inspections: 0,
+        }
+    }
+    fn inspect(&mut self, divisor: Option<i64>, modulo: Option<i64>) -> Vec<(i64, usize)> {
+        let mut throws: Vec<(i64, usize)> = Vec::new();
+        for item in &mut self.items {
+            trace!("  Monkey inspects an item with a worry level of {}.", item);
+            self.inspections += 1;
+            let starting_worry = match modulo {
+                Some(m) => *item % m,
+                None => *item,
+            };
+            let worry = match modulo {
+                Some(m) => self.operation.apply(starting_worry) / divisor.unwrap_or(1) % m,
+                None => self.operation.apply(starting_worry) / divisor.unwrap_or(1),
+            };
+            if worry < 0 {
+                panic!("Apply {:?} to {} gives {}", self.operation, item, worry);
+            }
+            trace!("    New worry is {}.", worry);
+            if worry % self.test == 0 {
+                trace!("    Current worry level is divisible by {}.", self.test);
+                throws.push((worry, self.if_true));
+                trace!(
+                    "    Item with worry level {} is thrown to monkey {}.",
+                    worry,
+                    self.if_true
+                );
+            } else {
+                trace!("    Current worry level is not divisible by {}.", self.test);
+                throws.push((worry, self.if_false));
+                trace!(
+                    "    Item with worry level {} is thrown to monkey {}.",
+                    worry,
+                    self.if_false
+                );
+            }
+        }
+        self.items.clear();
+        throws
+    }
+}
+
+pub struct Input {
+    monkeys: Vec<Monkey>,
+    /// Product of all the monkeys' test divisors.
+    modulo: i64,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut monkeys: Vec<Monkey> = Vec::new();
+    let mut modulo = 1;
+    for line in lines(input) {
+        let mut tokens = line.tokens();
+        let first = match tokens.next() {
+            Some(token) => token,
+            None => continue,
+        };
+        if first.text() == "Monkey" {
+            monkeys.push(Monkey::new());
+            tokens.next_token("monkey number")?;
+            tokens.end()?;
+            continue;
+        }
+        let monkey = match monkeys.last_mut() {
+            Some(monkey) => monkey,
+            None => return Err(first.error("`Monkey`")),
+        };
+        match first.text() {
+            "Starting" => {
+                tokens.expect("items:")?;
+                for token in tokens.by_ref() {
+                    let token = token.strip_suffix(",").unwrap_or(token);
+                    monkey.items.push(token.parse::<i64>("worry level")?);
+                }
+            }
+            "Operation:" => {
+                tokens.expect("new")?;
+                tokens.expect("=")?;
+                monkey.operation.op1 = Operand::parse(tokens.next_token("operand")?)?;
+                monkey.operation.operator = Operator::parse(tokens.next_token("operator")?)?;
+                monkey.operation.op2 = Operand::parse(tokens.next_token("operand")?)?;
+            }
+            "Test:" => {
+                tokens.expect("divisible")?;
+                tokens.expect("by")?;
+                monkey.test = tokens.parse_next::<i64>("divisor")?;
+                modulo *= monkey.test;
+            }
+            "If" => {
+                let condition = tokens.next_token("`true:` or `false:`")?;
+                for word in ["throw", "to", "monkey"] {
+                    tokens.expect(word)?;
+                }
+                let target = tokens.parse_next::<usize>("monkey number")?;
+                match condition.text() {
+                    "true:" => monkey.if_true = target,
+                    "false:" => monkey.if_false = target,
+                    _ => return Err(condition.error("`true:` or `false:`")),
+                }
+            }
+            _ => return Err(first.error("`Starting`, `Operation:`, `Test:` or `If`")),
+        }
+        tokens.end()?;
+    }
+    // Monkey business multiplies the two busiest monkeys' inspections.
+    if monkeys.len() < 2 {
+        return Err(ParseError::new(format!(
+            "at least two monkeys (found {})",
+            monkeys.len()
+        )));
+    }
+    for monkey in &monkeys {
+        trace!("{:?}", monkey);
+    }
+    Ok(Input { monkeys, modulo })
+}
+
+fn monkey_business(monkeys: &[Monkey]) -> i64 {
+    let mut inspections = monkeys.iter().map(|m| m.inspections).collect::<Vec<i64>>();
+    inspections.sort();
+    inspections.reverse();
+    inspections[0] * inspections[1]
+}
+
+pub fn solve_part1(input: &Input) -> Answer {
+    let mut monkeys = input.monkeys.clone();
+    for _ in 0..20 {
+        for i in 0..monkeys.len() {
+            trace!("Monkey {}:", i);
+            for throws in monkeys[i].inspect(Some(3), None) {
+                monkeys[throws.1].items.push(throws.0)
+            }
+        }
+    }
+    monkey_business(&monkeys).into()
+}
+
+pub fn solve_part2(input: &Input) -> Answer {
+    let mut monkeys = input.monkeys.clone();
+    for _ in 0..10000 {
+        for i in 0..monkeys.len() {
+            trace!("Monkey {}:", i);
+            for throws in monkeys[i].inspect(None, Some(input.modulo)) {
+                monkeys[throws.1].items.push(throws.0)
+            }
+        }
+    }
+    monkey_business(&monkeys).into()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
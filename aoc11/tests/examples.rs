@@ -0,0 +1,32 @@
+use aoc11::{solve_part1, solve_part2, Day11};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day11-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day11>(EXAMPLE)), Answer::from(10605));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(
+        solve_part2(&parse::<Day11>(EXAMPLE)),
+        Answer::from(2713310158_i64)
+    );
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day11>();
+}
+
+#[test]
+fn at_least_two_monkeys() {
+    let one = EXAMPLE.split("\n\n").next().unwrap();
+    for input in ["", one] {
+        let e = aoc11::parse(input).err().unwrap();
+        assert!(e.expected.starts_with("at least two monkeys"), "{}", e);
+    }
+}
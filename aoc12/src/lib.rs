@@ -0,0 +1,86 @@
+use common::grid::{Grid, Point};
+use common::search::{bfs, Found, Successors};
+use common::{Answer, ParseError, Solution};
+use log::trace;
+use std::collections::HashSet;
+
+pub struct Heightmap {
+    h: Grid<u8>,
+    s: Point,
+    e: Point,
+}
+
+impl Heightmap {
+    /// The fewest steps from any of `starts` to the end, if the end can be reached at all.
+    fn min_steps_from(&self, starts: impl IntoIterator<Item = Point>) -> Option<usize> {
+        let Found { value, path } = bfs(self, starts, |&p| p == self.e)?;
+        let path = path.into_iter().collect::<HashSet<_>>();
+        trace!(
+            "route:\n{}",
+            self.h
+                .render(|p, _| if path.contains(&p) { 'X' } else { '.' })
+        );
+        Some(value)
+    }
+}
+impl Successors for Heightmap {
+    type State = Point;
+    /// You can climb at most one step up, but drop any distance down.
+    fn successors(&self, &p: &Point) -> Vec<(Point, usize)> {
+        self.h
+            .neighbors4(p)
+            .filter(|&n| self.h[n] <= self.h[p] + 1)
+            .map(|n| (n, 1))
+            .collect()
+    }
+}
+
+pub type Input = Heightmap;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let map = Grid::parse(input, "elevation (a-z, S or E)", |c| {
+        (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)
+    })?;
+    let s = map
+        .position(|&c| c == 'S')
+        .ok_or(ParseError::new("a start (S)"))?;
+    let e = map
+        .position(|&c| c == 'E')
+        .ok_or(ParseError::new("an end (E)"))?;
+    let h = map.map(|_, &c| match c {
+        'S' => 0,
+        'E' => 25,
+        c => c as u8 - b'a',
+    });
+    Ok(Heightmap { h, s, e })
+}
+
+/// The fewest steps, or says the end can't be reached; some maps parse but wall E off.
+fn answer(steps: Option<usize>) -> Answer {
+    steps.map_or_else(|| Answer::Text("no route".to_string()), Answer::from)
+}
+
+pub fn solve_part1(hm: &Input) -> Answer {
+    answer(hm.min_steps_from([hm.s]))
+}
+
+pub fn solve_part2(hm: &Input) -> Answer {
+    let lowest = hm.h.iter().filter(|(_, &h)| h == 0).map(|(p, _)| p);
+    answer(hm.min_steps_from(lowest))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
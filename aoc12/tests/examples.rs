@@ -0,0 +1,27 @@
+use aoc12::{solve_part1, solve_part2, Day12};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day12-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day12>(EXAMPLE)), Answer::from(31));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day12>(EXAMPLE)), Answer::from(29));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day12>();
+}
+
+#[test]
+fn unreachable_end() {
+    let hm = parse::<Day12>("Sabc\nbcdE\n");
+    assert_eq!(solve_part1(&hm), Answer::Text("no route".into()));
+    assert_eq!(solve_part2(&hm), Answer::Text("no route".into()));
+}
@@ -0,0 +1,134 @@
+use common::parse::{lines, Line};
+use common::{Answer, ParseError, Solution};
+use log::debug;
+use serde_json::Value;
+use std::cmp::Ordering;
+
+fn compare(a: &Value, b: &Value) -> std::cmp::Ordering {
+    match a {
+        Value::Number(aa) => match b {
+            Value::Number(bb) => aa.as_i64().unwrap().cmp(&bb.as_i64().unwrap()),
+            Value::Array(_) => compare(&Value::Array(vec![Value::Number(aa.clone())]), b),
+            _ => panic!(),
+        },
+        Value::Array(aa) => match b {
+            Value::Number(bb) => compare(a, &Value::Array(vec![Value::Number(bb.clone())])),
+            Value::Array(bb) => {
+                for i in 0..std::cmp::min(aa.len(), bb.len()) {
+                    let ordering = compare(&aa[i], &bb[i]);
+                    if let Ordering::Equal = ordering {
+                        continue;
+                    }
+                    return ordering;
+                }
+                aa.len().cmp(&bb.len())
+            }
+            _ => panic!(),
+        },
+        _ => panic!(),
+    }
+}
+
+/// Pairs of packets, as parsed JSON.
+pub type Input = Vec<(Value, Value)>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut pairs = Vec::new();
+    let mut lines = lines(input);
+    while let Some(line) = lines.next() {
+        let a = parse_packet(line)?;
+        let b = match lines.next() {
+            Some(line) => parse_packet(line)?,
+            None => return Err(line.error_at_end("a second packet on the next line")),
+        };
+        if let Some(line) = lines.next() {
+            if !line.is_empty() {
+                return Err(line.error_at(1, "blank line between pairs"));
+            }
+        }
+        pairs.push((a, b));
+    }
+    Ok(pairs)
+}
+
+fn parse_packet(line: Line) -> Result<Value, ParseError> {
+    let packet = serde_json::from_str(line.text).map_err(|e| {
+        // serde_json appends its own "at line L column C", which we report separately.
+        let message = e.to_string();
+        let message = message.split(" at line ").next().unwrap_or_default();
+        let column = e.column().clamp(1, line.text.len() + 1);
+        line.error_at(column, format!("packet ({})", message))
+    })?;
+    // `compare` only knows integers and lists, so anything else JSON allows is an error here.
+    let mut pending = vec![&packet];
+    while let Some(value) = pending.pop() {
+        let found = match value {
+            Value::Number(n) if n.as_i64().is_some() => continue,
+            Value::Array(items) => {
+                pending.extend(items);
+                continue;
+            }
+            Value::Number(n) => format!("the number {}", n),
+            Value::Null => "null".to_string(),
+            Value::Bool(b) => b.to_string(),
+            Value::String(_) => "a string".to_string(),
+            Value::Object(_) => "an object".to_string(),
+        };
+        return Err(line.error_at(
+            1,
+            format!("packet of integers and lists (it has {})", found),
+        ));
+    }
+    Ok(packet)
+}
+
+pub fn solve_part1(pairs: &Input) -> Answer {
+    let mut idx_sum = 0;
+    for (idx, (a, b)) in pairs.iter().enumerate() {
+        debug!(
+            "pair {}: {} {} {}",
+            idx + 1,
+            a,
+            match compare(a, b) {
+                Ordering::Less => "<",
+                Ordering::Equal => "==",
+                Ordering::Greater => ">",
+            },
+            b
+        );
+        if let Ordering::Less = compare(a, b) {
+            idx_sum += idx + 1;
+        }
+    }
+    idx_sum.into()
+}
+
+pub fn solve_part2(pairs: &Input) -> Answer {
+    let d1: Value = serde_json::from_str("[[2]]").unwrap();
+    let d2: Value = serde_json::from_str("[[6]]").unwrap();
+    let mut packets: Vec<Value> = vec![d1.clone(), d2.clone()];
+    for (a, b) in pairs {
+        packets.push(a.clone());
+        packets.push(b.clone());
+    }
+    packets.sort_by(compare);
+    ((packets.binary_search_by(|a| compare(a, &d1)).unwrap() + 1)
+        * (packets.binary_search_by(|a| compare(a, &d2)).unwrap() + 1))
+        .into()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
@@ -0,0 +1,39 @@
+use aoc13::{solve_part1, solve_part2, Day13};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day13-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day13>(EXAMPLE)), Answer::from(13));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day13>(EXAMPLE)), Answer::from(140));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day13>();
+}
+
+#[test]
+fn packets_hold_only_integers_and_lists() {
+    for (packet, found) in [
+        ("[1.5]", "the number 1.5"),
+        ("[{\"a\":1}]", "an object"),
+        ("[[1],[true]]", "true"),
+        ("[null]", "null"),
+        ("[\"x\"]", "a string"),
+    ] {
+        let input = format!("{}\n[1]\n", packet);
+        let e = aoc13::parse(&input).unwrap_err();
+        assert_eq!(
+            e.expected,
+            format!("packet of integers and lists (it has {})", found)
+        );
+    }
+    assert!(aoc13::parse("[[1],[2,[3,-4]]]\n[]\n").is_ok());
+}
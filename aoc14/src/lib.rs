@@ -0,0 +1,200 @@
+use common::grid::{Grid, Point};
+use common::parse::lines;
+use common::{Answer, ParseError, Solution};
+use log::{debug, trace};
+use std::fmt;
+
+#[derive(Clone)]
+enum Contents {
+    Empty,
+    Rock,
+    Sand,
+}
+impl fmt::Display for Contents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Contents::Empty => ".",
+            Contents::Rock => "#",
+            Contents::Sand => "o",
+        })
+    }
+}
+
+#[derive(Debug)]
+enum DropOutcome {
+    Escaped,
+    Position(Point),
+}
+
+const SOURCE: Point = Point::new(500, 0);
+
+#[derive(Clone)]
+pub struct Cave {
+    /// Two rows deeper than the lowest rock, to leave room for the floor in part 2.
+    c: Grid<Contents>,
+    ymax: i32,
+}
+impl Cave {
+    fn parse(input: &str) -> Result<Cave, ParseError> {
+        let mut rocks = Vec::new();
+        let mut xmin = 0;
+        let mut xmax = 0;
+        let mut ymax = 0;
+        for line in lines(input) {
+            if line.is_empty() {
+                continue;
+            }
+            let mut cur_scan: Vec<Point> = Vec::new();
+            let mut tokens = line.tokens();
+            loop {
+                let t = tokens.next_token("point (`x,y`)")?;
+                let (x, y_token) = t.split_once(",")?;
+                let x = x.parse::<i32>("x coordinate")?;
+                let y = y_token.parse::<i32>("y coordinate")?;
+                if y < 0 {
+                    return Err(
+                        y_token.error("y coordinate of 0 or more (y counts down from the top)")
+                    );
+                }
+                let p = Point::new(x, y);
+                if let Some(&from) = cur_scan.last() {
+                    if from.x != p.x && from.y != p.y {
+                        let expected =
+                            format!("a point in line with {},{} (no diagonals)", from.x, from.y);
+                        return Err(t.error(expected));
+                    }
+                }
+                cur_scan.push(p);
+                if xmin == 0 || x < xmin {
+                    xmin = x;
+                }
+                if x > xmax {
+                    xmax = x;
+                }
+                if y > ymax {
+                    ymax = y;
+                }
+                match tokens.next() {
+                    Some(arrow) => arrow.expect("->")?,
+                    None => break,
+                }
+            }
+            rocks.push(cur_scan);
+        }
+        // Sand piles up at most 45 degrees either side of the source, and the floor is at most
+        // ymax + 2 down.
+        xmin = std::cmp::min(xmin, SOURCE.x) - (ymax + 2);
+        xmax = std::cmp::max(xmax, SOURCE.x) + (ymax + 2);
+        trace!("{:?}", rocks);
+        trace!("xmin = {}, xmax = {}, ymax = {}", xmin, xmax, ymax);
+        let mut cave = Cave {
+            c: Grid::with_origin(
+                Point::new(xmin, 0),
+                (xmax - xmin + 1) as usize,
+                (ymax + 3) as usize,
+                Contents::Empty,
+            ),
+            ymax,
+        };
+        for scan in &rocks {
+            for c in 1..scan.len() {
+                let from = scan[c - 1];
+                let to = scan[c];
+                trace!("from {} to {}", from, to);
+                for xx in std::cmp::min(from.x, to.x)..=std::cmp::max(from.x, to.x) {
+                    cave.c[Point::new(xx, from.y)] = Contents::Rock;
+                }
+                for yy in std::cmp::min(from.y, to.y)..=std::cmp::max(from.y, to.y) {
+                    cave.c[Point::new(from.x, yy)] = Contents::Rock;
+                }
+            }
+        }
+        Ok(cave)
+    }
+    fn add_floor(&mut self) {
+        self.ymax += 2;
+        for x in self.c.origin().x..=self.c.end().x {
+            self.c[Point::new(x, self.ymax)] = Contents::Rock;
+        }
+    }
+    fn drop(&mut self, pos: Point) -> DropOutcome {
+        trace!("drop {}", pos);
+        if self.escaped(pos) {
+            trace!("escaped");
+            return DropOutcome::Escaped;
+        }
+        for t in [
+            pos + Point::DOWN,
+            pos + Point::DOWN + Point::LEFT,
+            pos + Point::DOWN + Point::RIGHT,
+        ] {
+            trace!("trying {}", t);
+            if self.escaped(t) {
+                trace!("escaped");
+                return DropOutcome::Escaped;
+            } else if let Contents::Empty = self.c[t] {
+                trace!("empty at {}", t);
+                return self.drop(t);
+            }
+        }
+        trace!("final position {}", pos);
+        self.c[pos] = Contents::Sand;
+        DropOutcome::Position(pos)
+    }
+    fn escaped(&self, pos: Point) -> bool {
+        pos.y > self.ymax
+    }
+}
+impl fmt::Display for Cave {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.c.fmt(f)
+    }
+}
+
+pub type Input = Cave;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    Cave::parse(input)
+}
+
+pub fn solve_part1(cave: &Input) -> Answer {
+    let mut c1 = cave.clone();
+    let mut t = 0;
+    while let DropOutcome::Position(_) = c1.drop(SOURCE) {
+        trace!("\n{}", c1);
+        t += 1;
+    }
+    debug!("\n{}", c1);
+    t.into()
+}
+
+pub fn solve_part2(cave: &Input) -> Answer {
+    let mut c2 = cave.clone();
+    c2.add_floor();
+    let mut t = 0;
+    while let DropOutcome::Position(pos) = c2.drop(SOURCE) {
+        trace!("\n{}", c2);
+        t += 1;
+        if pos == SOURCE {
+            break;
+        }
+    }
+    debug!("\n{}", c2);
+    t.into()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
@@ -0,0 +1,44 @@
+use aoc14::{solve_part1, solve_part2, Day14};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day14-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day14>(EXAMPLE)), Answer::from(24));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day14>(EXAMPLE)), Answer::from(93));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day14>();
+}
+
+#[test]
+fn rock_paths_are_checked() {
+    let error = |input: &str| {
+        let e = aoc14::parse(input).err().unwrap();
+        let location = e.location.unwrap();
+        (location.line, location.column, e.expected)
+    };
+    assert_eq!(
+        error("498,-4 -> 498,6\n"),
+        (
+            1,
+            5,
+            "y coordinate of 0 or more (y counts down from the top)".into()
+        )
+    );
+    assert_eq!(
+        error("498,4 -> 498,6\n498,4 -> 500,6\n"),
+        (2, 10, "a point in line with 498,4 (no diagonals)".into())
+    );
+    assert_eq!(error("498,4 -> 498,6 ->\n").2, "point (`x,y`)");
+    assert_eq!(error("498,4 498,6\n").2, "`->`");
+    assert!(aoc14::parse("-3,4 -> 498,4\n").is_ok());
+}
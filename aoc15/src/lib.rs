@@ -0,0 +1,210 @@
+use common::interval::{Interval, IntervalSet};
+use common::parse::{lines, Token};
+use common::{Answer, ParseError, Solution};
+use log::{debug, trace};
+use std::collections::HashSet;
+struct Sensor {
+    loc: (i32, i32),
+    closest_beacon: (i32, i32),
+}
+impl Sensor {
+    fn distance_to(&self, loc: (i32, i32)) -> i32 {
+        manhattan_distance(loc, self.loc)
+    }
+    fn distance_to_beacon(&self) -> i32 {
+        self.distance_to(self.closest_beacon)
+    }
+    /// The positions on row `y` no closer to the sensor than its beacon.
+    fn impossible_range(&self, y: i32) -> Option<Interval> {
+        let reach = (self.distance_to_beacon() - (self.loc.1 - y).abs()) as i64;
+        Interval::new(self.loc.0 as i64 - reach, self.loc.0 as i64 + reach)
+    }
+}
+
+fn manhattan_distance(p1: (i32, i32), p2: (i32, i32)) -> i32 {
+    (p2.0 - p1.0).abs() + (p2.1 - p1.1).abs()
+}
+
+pub struct SensorNetwork {
+    ss: Vec<Sensor>,
+    sensor_locs: HashSet<(i32, i32)>,
+    beacon_locs: HashSet<(i32, i32)>,
+    xmin: i32,
+    xmax: i32,
+    ymin: i32,
+    ymax: i32,
+}
+impl SensorNetwork {
+    fn new() -> SensorNetwork {
+        SensorNetwork {
+            ss: Vec::new(),
+            sensor_locs: HashSet::new(),
+            beacon_locs: HashSet::new(),
+            xmin: 0,
+            xmax: 0,
+            ymin: 0,
+            ymax: 0,
+        }
+    }
+    fn add_sensor(&mut self, s: Sensor) {
+        if self.ss.is_empty() {
+            self.xmin = std::cmp::min(s.loc.0, s.closest_beacon.0);
+            self.xmax = std::cmp::max(s.loc.0, s.closest_beacon.0);
+            self.ymin = std::cmp::min(s.loc.1, s.closest_beacon.1);
+            self.ymax = std::cmp::max(s.loc.1, s.closest_beacon.1);
+        } else {
+            self.xmin = std::cmp::min(self.xmin, s.loc.0 - s.distance_to_beacon());
+            self.xmin = std::cmp::min(self.xmin, s.closest_beacon.0);
+            self.xmax = std::cmp::max(self.xmax, s.loc.0 + s.distance_to_beacon());
+            self.xmax = std::cmp::max(self.xmax, s.closest_beacon.0);
+            self.ymin = std::cmp::min(self.ymin, s.loc.1 - s.distance_to_beacon());
+            self.ymin = std::cmp::min(self.ymin, s.closest_beacon.1);
+            self.ymax = std::cmp::max(self.ymax, s.loc.1 + s.distance_to_beacon());
+            self.ymax = std::cmp::max(self.ymax, s.closest_beacon.1);
+        }
+        self.sensor_locs.insert(s.loc);
+        self.beacon_locs.insert(s.closest_beacon);
+        self.ss.push(s);
+    }
+    fn has_sensor_at(&self, pos: (i32, i32)) -> bool {
+        self.sensor_locs.contains(&pos)
+    }
+    fn has_beacon_at(&self, pos: (i32, i32)) -> bool {
+        self.beacon_locs.contains(&pos)
+    }
+    /// The positions on row `y` within range of some sensor.
+    fn coverage(&self, y: i32) -> IntervalSet {
+        self.ss
+            .iter()
+            .filter_map(|s| s.impossible_range(y))
+            .collect()
+    }
+    fn log_bounds(&self) {
+        debug!(
+            "xmin = {}, xmax = {}, diff = {}",
+            self.xmin,
+            self.xmax,
+            self.xmax - self.xmin + 1
+        );
+        debug!(
+            "ymin = {}, ymax = {}, diff = {}",
+            self.ymin,
+            self.ymax,
+            self.ymax - self.ymin + 1
+        );
+        // Only sensible for the example; the real input is millions of positions wide.
+        for y in self.ymin..=self.ymax {
+            trace!(
+                "{:>10} {}",
+                y,
+                (self.xmin..=self.xmax)
+                    .map(|x| if self.has_sensor_at((x, y)) {
+                        'S'
+                    } else if self.has_beacon_at((x, y)) {
+                        'B'
+                    } else {
+                        '.'
+                    })
+                    .collect::<String>()
+            );
+        }
+    }
+}
+
+pub type Input = SensorNetwork;
+
+fn coordinate(token: Token, prefix: &str) -> Result<i32, ParseError> {
+    token.strip_prefix(prefix)?.parse::<i32>("integer")
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut sensors = SensorNetwork::new();
+    for line in lines(input) {
+        let mut tokens = line.tokens();
+        tokens.expect("Sensor")?;
+        tokens.expect("at")?;
+        let sx = coordinate(tokens.next_token("`x=`")?.strip_suffix(",")?, "x=")?;
+        let sy = coordinate(tokens.next_token("`y=`")?.strip_suffix(":")?, "y=")?;
+        for word in ["closest", "beacon", "is", "at"] {
+            tokens.expect(word)?;
+        }
+        let bx = coordinate(tokens.next_token("`x=`")?.strip_suffix(",")?, "x=")?;
+        let by = coordinate(tokens.next_token("`y=`")?, "y=")?;
+        tokens.end()?;
+        let s = Sensor {
+            loc: (sx, sy),
+            closest_beacon: (bx, by),
+        };
+        debug!(
+            "sensor: {:?}, closest beacon: {:?}, distance: {}",
+            s.loc,
+            s.closest_beacon,
+            s.distance_to_beacon()
+        );
+        sensors.add_sensor(s);
+    }
+    sensors.log_bounds();
+    Ok(sensors)
+}
+
+pub fn solve_part1(sensors: &Input) -> Answer {
+    solve_part1_at_row(sensors, 2000000)
+}
+
+/// Counts the positions on row `y` that cannot contain a beacon: those covered by a sensor, less
+/// the beacons already known to be there. The puzzle asks about row 2000000, but the example uses
+/// row 10.
+pub fn solve_part1_at_row(sensors: &Input, y: i32) -> Answer {
+    let covered = sensors.coverage(y);
+    let beacons = sensors
+        .beacon_locs
+        .iter()
+        .filter(|b| b.1 == y && covered.contains(b.0 as i64))
+        .count();
+    let num_invalid = covered.len() - beacons as u128;
+    debug!(
+        "{} positions on row {} cannot contain a beacon",
+        num_invalid, y
+    );
+    num_invalid.into()
+}
+
+pub fn solve_part2(sensors: &Input) -> Answer {
+    solve_part2_within(sensors, 4000000)
+}
+
+/// Finds the tuning frequency of the only possible beacon position with both coordinates in
+/// `0..=max`. The puzzle uses 4000000, the example 20.
+pub fn solve_part2_within(sensors: &Input, max: i32) -> Answer {
+    let row = Interval::new(0, max as i64).unwrap();
+    for y in 0..=max {
+        let gaps = sensors.coverage(y).gaps(row);
+        if let Some(gap) = gaps.intervals().first() {
+            debug!("gap on row {}: {}", y, gaps);
+            return (y as i64 + 4000000 * gap.start()).into();
+        }
+    }
+    panic!("No gap found");
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+    fn example_part1(input: &Input) -> Answer {
+        solve_part1_at_row(input, 10)
+    }
+    fn example_part2(input: &Input) -> Answer {
+        solve_part2_within(input, 20)
+    }
+}
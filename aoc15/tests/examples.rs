@@ -0,0 +1,33 @@
+use aoc15::{solve_part1_at_row, solve_part2_within, Day15};
+use common::testing::{check_real_input, parse};
+use common::{Answer, Day};
+
+const EXAMPLE: &str = include_str!("../../examples/day15-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(
+        solve_part1_at_row(&parse::<Day15>(EXAMPLE), 10),
+        Answer::from(26)
+    );
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(
+        solve_part2_within(&parse::<Day15>(EXAMPLE), 20),
+        Answer::from(56000011)
+    );
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day15>();
+}
+
+#[test]
+fn runner_uses_the_example_constants() {
+    let answers = Day15.run_example(EXAMPLE).unwrap();
+    assert_eq!(answers.part1, Answer::from(26));
+    assert_eq!(answers.part2, Answer::from(56000011));
+}
@@ -0,0 +1,237 @@
+use common::parse::lines;
+use common::search::{branch_and_bound, Bounded};
+use common::{Answer, ParseError, Solution};
+use log::{debug, trace};
+use petgraph::algo::floyd_warshall;
+use petgraph::dot::Dot;
+use petgraph::graph::Graph;
+use petgraph::stable_graph::NodeIndex;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+
+#[derive(Debug)]
+struct FlowNode {
+    name: String,
+    flow: i32,
+}
+impl fmt::Display for FlowNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}", self.name, self.flow)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PathPrefix {
+    min_flow: i32,
+    max_flow: i32,
+    time_remaining: i32,
+    time_remaining_elephant: i32,
+    path: Vec<NodeIndex>,
+    path_elephant: Vec<NodeIndex>,
+    unvisited: BTreeSet<NodeIndex>,
+}
+impl PathPrefix {
+    fn new(g: &Graph<FlowNode, i32>, start: NodeIndex, time_remaining: i32) -> PathPrefix {
+        let mut p = PathPrefix {
+            min_flow: 0,
+            max_flow: 0,
+            time_remaining,
+            time_remaining_elephant: time_remaining,
+            path: vec![start],
+            path_elephant: vec![start],
+            unvisited: BTreeSet::new(),
+        };
+        for i in g.node_indices() {
+            if i != start {
+                p.unvisited.insert(i);
+                p.max_flow += g[i].flow * time_remaining;
+            }
+        }
+        p
+    }
+    fn in_time(&self) -> bool {
+        self.time_remaining >= 0 && self.time_remaining_elephant >= 0
+    }
+    fn append(&self, g: &Graph<FlowNode, i32>, next: NodeIndex) -> PathPrefix {
+        let mut p = self.clone();
+        p.unvisited.remove(&next);
+        let edge = g.find_edge(*p.path.last().unwrap(), next).unwrap();
+        p.path.push(next);
+        let flow = g[next].flow;
+        let time_cost = g.edge_weight(edge).unwrap() + 1;
+        p.time_remaining -= time_cost;
+        p.min_flow += flow * p.time_remaining;
+        p.max_flow = p.min_flow;
+        for i in &p.unvisited {
+            let time_cost = g.edge_weight(g.find_edge(next, *i).unwrap()).unwrap() + 1;
+            p.max_flow += g[*i].flow
+                * std::cmp::max(
+                    std::cmp::max(p.time_remaining, p.time_remaining_elephant) - time_cost,
+                    0,
+                );
+        }
+        p
+    }
+    fn append_elephant(&self, g: &Graph<FlowNode, i32>, next: NodeIndex) -> PathPrefix {
+        let mut p = self.clone();
+        p.unvisited.remove(&next);
+        let edge = g.find_edge(*p.path_elephant.last().unwrap(), next).unwrap();
+        p.path_elephant.push(next);
+        let flow = g[next].flow;
+        let time_cost = g.edge_weight(edge).unwrap() + 1;
+        p.time_remaining_elephant -= time_cost;
+        p.min_flow += flow * p.time_remaining_elephant;
+        p.max_flow = p.min_flow;
+        for i in &p.unvisited {
+            let time_cost = g.edge_weight(g.find_edge(next, *i).unwrap()).unwrap() + 1;
+            p.max_flow += g[*i].flow
+                * std::cmp::max(
+                    std::cmp::max(p.time_remaining, p.time_remaining_elephant) - time_cost,
+                    0,
+                );
+        }
+        p
+    }
+}
+
+/// Opening valves one at a time, alone or with the elephant's help. `min_flow` is the pressure
+/// released by the valves opened so far, and `max_flow` bounds what opening the rest could add.
+struct Valves<'a> {
+    g: &'a Graph<FlowNode, i32>,
+    elephant: bool,
+}
+impl Bounded for Valves<'_> {
+    type State = PathPrefix;
+    type Value = i32;
+    fn successors(&self, p: &PathPrefix) -> Vec<PathPrefix> {
+        let mut next = Vec::new();
+        for node in &p.unvisited {
+            next.push(p.append(self.g, *node));
+            if self.elephant {
+                next.push(p.append_elephant(self.g, *node));
+            }
+        }
+        next.retain(PathPrefix::in_time);
+        next.sort_by_key(|p| std::cmp::Reverse(p.max_flow));
+        next
+    }
+    fn value(&self, p: &PathPrefix) -> i32 {
+        p.min_flow
+    }
+    fn bound(&self, p: &PathPrefix) -> i32 {
+        p.max_flow
+    }
+}
+
+/// The valves with non-zero flow, plus the start valve `AA`, with edges weighted by the shortest
+/// travel time between them.
+pub struct Input {
+    g: Graph<FlowNode, i32>,
+    start: NodeIndex,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut g = Graph::new();
+    let mut indices = HashMap::new();
+    for line in lines(input) {
+        let mut tokens = line.tokens();
+        tokens.expect("Valve")?;
+        let vname = tokens.next_token("valve name")?.text().to_string();
+        tokens.expect("has")?;
+        tokens.expect("flow")?;
+        let flow = tokens
+            .next_token("`rate=`")?
+            .strip_suffix(";")?
+            .strip_prefix("rate=")?
+            .parse::<i32>("flow rate")?;
+        let from = *indices
+            .entry(vname.clone())
+            .or_insert_with(|| g.add_node(FlowNode { name: vname, flow }));
+        let tunnels = tokens.next_token("`tunnels`")?;
+        match tunnels.text() {
+            "tunnel" => {
+                tokens.expect("leads")?;
+                tokens.expect("to")?;
+                tokens.expect("valve")?;
+            }
+            "tunnels" => {
+                tokens.expect("lead")?;
+                tokens.expect("to")?;
+                tokens.expect("valves")?;
+            }
+            _ => return Err(tunnels.error("`tunnel` or `tunnels`")),
+        }
+        for token in tokens {
+            let token = token.strip_suffix(",").unwrap_or(token).text().to_string();
+            let to = *indices.entry(token.clone()).or_insert_with(|| {
+                g.add_node(FlowNode {
+                    name: token,
+                    flow: 0,
+                })
+            });
+            // petgraph's Floyd-Warshall implementation doesn't work with undirected graphs.
+            g.update_edge(from, to, 1);
+            g.update_edge(to, from, 1);
+        }
+        g[from].flow = flow;
+        g.node_weight_mut(from).unwrap().flow = flow;
+    }
+    trace!("{}", Dot::new(&g));
+    let shortest_paths = floyd_warshall(&g, |_| 1).unwrap();
+    trace!("{:?}", shortest_paths);
+    trace!("{} indices, {} paths", indices.len(), shortest_paths.len());
+    g.retain_nodes(|g, n| g[n].flow > 0 || g[n].name == "AA");
+    trace!("{}", Dot::new(&g));
+    let start = g
+        .node_indices()
+        .find(|&i| g[i].name == "AA")
+        .ok_or_else(|| ParseError::new("a valve named AA"))?;
+    let mut all_indices = HashSet::new();
+    for i in g.node_indices() {
+        if i != start {
+            all_indices.insert(i);
+        }
+        for j in g.node_indices() {
+            if i != j {
+                g.update_edge(
+                    i,
+                    j,
+                    shortest_paths[&(indices[&g[i].name], indices[&g[j].name])],
+                );
+            }
+        }
+    }
+    trace!("{}", Dot::new(&g));
+    Ok(Input { g, start })
+}
+
+fn most_pressure(input: &Input, time: i32, elephant: bool) -> i32 {
+    let Input { g, start } = input;
+    let best = branch_and_bound(&Valves { g, elephant }, PathPrefix::new(g, *start, time));
+    debug!("best = {}, {:?}", best.value, best.path.last());
+    best.value
+}
+
+pub fn solve_part1(input: &Input) -> Answer {
+    most_pressure(input, 30, false).into()
+}
+
+pub fn solve_part2(input: &Input) -> Answer {
+    most_pressure(input, 26, true).into()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
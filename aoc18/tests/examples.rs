@@ -0,0 +1,20 @@
+use aoc18::{solve_part1, solve_part2, Day18};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day18-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day18>(EXAMPLE)), Answer::from(64));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day18>(EXAMPLE)), Answer::from(58));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day18>();
+}
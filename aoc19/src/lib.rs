@@ -0,0 +1,374 @@
+use common::parse::{lines, Tokens};
+use common::search::{branch_and_bound, Bounded};
+use common::{Answer, ParseError, Solution};
+use log::{debug, trace};
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct Blueprint {
+    id: i32,
+    ore_cost: i32,
+    clay_cost: i32,
+    obs_ore_cost: i32,
+    obs_clay_cost: i32,
+    geode_ore_cost: i32,
+    geode_obs_cost: i32,
+}
+impl Blueprint {
+    fn max_robots(&self, t: usize) -> i32 {
+        match t {
+            OBSIDIAN => self.geode_obs_cost,
+            CLAY => self.obs_clay_cost,
+            ORE => self.geode_ore_cost + self.obs_ore_cost + self.clay_cost + self.ore_cost,
+            _ => panic!(),
+        }
+    }
+    fn find_best(&self, max_t: i32) -> i32 {
+        debug!("{:?}", self);
+
+        let bc = BestCase::from(self, max_t);
+        bc.log();
+        if bc.data.last().unwrap().resources[GEODE] == 0 {
+            debug!("No geodes possible");
+            return 0;
+        }
+
+        let mut start = State::new(max_t);
+        start.geode_lower_limit = start.predict_resource(GEODE);
+        start.geode_upper_limit = start.geode_upper_bound(&bc);
+        let best = branch_and_bound(&Factory { b: self, bc }, start);
+        State::print_header();
+        for s in &best.path {
+            debug!("{}", s);
+        }
+        best.value
+    }
+}
+
+/// Choosing what, if anything, to build each minute. A state's lower limit is what it will have
+/// collected by the end if nothing more is built, and its upper limit is what it could collect at
+/// best.
+struct Factory<'a> {
+    b: &'a Blueprint,
+    bc: BestCase,
+}
+impl Bounded for Factory<'_> {
+    type State = State;
+    type Value = i32;
+    fn successors(&self, s: &State) -> Vec<State> {
+        trace!("{}", s);
+        let mut states = s.next_states(self.b, &self.bc);
+        // Geode robots first, then the robots they need, and waiting last.
+        states.reverse();
+        states
+    }
+    fn value(&self, s: &State) -> i32 {
+        s.geode_lower_limit
+    }
+    fn bound(&self, s: &State) -> i32 {
+        s.geode_upper_limit
+    }
+}
+
+const GEODE: usize = 0;
+const OBSIDIAN: usize = 1;
+const CLAY: usize = 2;
+const ORE: usize = 3;
+const ALL_TYPES: [usize; 4] = [ORE, CLAY, OBSIDIAN, GEODE];
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct State {
+    geode_upper_limit: i32,
+    geode_lower_limit: i32,
+    robots: [i32; 4],
+    resources: [i32; 4],
+    time: i32,
+    tr: i32,
+}
+impl State {
+    fn new(max_t: i32) -> State {
+        State {
+            geode_lower_limit: 0,
+            geode_upper_limit: 0,
+            robots: [0, 0, 0, 1],
+            resources: [0, 0, 0, 0],
+            time: 0,
+            tr: max_t,
+        }
+    }
+    fn print_header() {
+        debug!("+---------+---------+---------+---------+---------+---------+---------+---------+---------+---------+---------+---------+");
+        debug!(
+            "|{:>9}|{:>9}|{:>9}|{:>9}|{:>9}|{:>9}|{:>9}|{:>9}|{:>9}|{:>9}|{:>9}|{:>9}|",
+            "time",
+            "tr",
+            "g ll",
+            "g ul",
+            "geodes",
+            "geode r",
+            "obs",
+            "obs r",
+            "clay",
+            "clay r",
+            "ore",
+            "ore r"
+        );
+        debug!("+---------+---------+---------+---------+---------+---------+---------+---------+---------+---------+---------+---------+");
+    }
+    fn can_build_n(&self, t: usize, b: &Blueprint, n: i32) -> bool {
+        if t != GEODE && self.robots[t] >= b.max_robots(t) {
+            false
+        } else {
+            match t {
+                GEODE => {
+                    self.resources[ORE] >= n * b.geode_ore_cost
+                        && self.resources[OBSIDIAN] >= n * b.geode_obs_cost
+                }
+                OBSIDIAN => {
+                    self.resources[ORE] >= n * b.obs_ore_cost
+                        && self.resources[CLAY] >= n * b.obs_clay_cost
+                }
+                CLAY => self.resources[ORE] >= n * b.clay_cost,
+                ORE => self.resources[ORE] >= n * b.ore_cost,
+                _ => panic!(),
+            }
+        }
+    }
+    fn can_build(&self, t: usize, b: &Blueprint) -> bool {
+        self.can_build_n(t, b, 1)
+    }
+    #[allow(dead_code)]
+    fn can_build_anything(&self, b: &Blueprint) -> bool {
+        for t in ALL_TYPES {
+            if self.can_build(t, b) {
+                return true;
+            }
+        }
+        false
+    }
+    fn next_states(&self, b: &Blueprint, bc: &BestCase) -> Vec<State> {
+        let mut states = Vec::new();
+        if self.tr > 1 {
+            states.push(self.do_nothing());
+            for t in ALL_TYPES {
+                if self.can_build(t, b) {
+                    trace!("Can build {:?}", t);
+                    states.push(self.build_robot(t, b));
+                }
+            }
+        }
+        if states.is_empty() && self.tr > 0 {
+            states.push(self.do_nothing());
+        }
+        for s in &mut states {
+            s.geode_lower_limit = s.predict_resource(GEODE);
+            s.geode_upper_limit = s.geode_upper_bound(bc);
+            if s.geode_lower_limit > s.geode_upper_limit {
+                panic!()
+            }
+        }
+        trace!("{} next options", states.len());
+        states
+    }
+    fn do_nothing(&self) -> State {
+        let mut s = self.clone();
+        s.time += 1;
+        s.tr -= 1;
+        for t in ALL_TYPES {
+            s.resources[t] += s.robots[t];
+        }
+        s
+    }
+    fn build_robot(&self, t: usize, b: &Blueprint) -> State {
+        let mut s = self.do_nothing();
+        s.robots[t] += 1;
+        match t {
+            GEODE => {
+                s.resources[ORE] -= b.geode_ore_cost;
+                s.resources[OBSIDIAN] -= b.geode_obs_cost;
+            }
+            OBSIDIAN => {
+                s.resources[ORE] -= b.obs_ore_cost;
+                s.resources[CLAY] -= b.obs_clay_cost;
+            }
+            CLAY => {
+                s.resources[ORE] -= b.clay_cost;
+            }
+            ORE => {
+                s.resources[ORE] -= b.ore_cost;
+            }
+            _ => panic!(),
+        }
+        s
+    }
+    fn predict_resource(&self, t: usize) -> i32 {
+        self.resources[t] + self.robots[t] * self.tr
+    }
+    fn geode_upper_bound(&self, bc: &BestCase) -> i32 {
+        std::cmp::min(
+            self.predict_resource(GEODE) + self.tr * (self.tr - 1) / 2,
+            bc.most_geodes(self),
+        )
+    }
+}
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "|{:9}|{:9}|{:9}|{:9}|{:9}|{:9}|{:9}|{:9}|{:9}|{:9}|{:9}|{:9}|",
+            self.time,
+            self.tr,
+            self.geode_lower_limit,
+            self.geode_upper_limit,
+            self.resources[GEODE],
+            self.robots[GEODE],
+            self.resources[OBSIDIAN],
+            self.robots[OBSIDIAN],
+            self.resources[CLAY],
+            self.robots[CLAY],
+            self.resources[ORE],
+            self.robots[ORE],
+        )
+    }
+}
+
+struct BestCase {
+    data: Vec<State>,
+    first_robot: [i32; 4],
+}
+impl BestCase {
+    fn from(b: &Blueprint, max_t: i32) -> BestCase {
+        let mut best_case = vec![State::new(max_t)];
+        let mut first_robot: [i32; 4] = [0, 0, 0, 0];
+        trace!("{}", best_case.last().unwrap());
+        for time in 1..=max_t {
+            let last = best_case.last().unwrap();
+            let mut s = best_case.last().unwrap().do_nothing();
+            for t in ALL_TYPES {
+                if last.can_build_n(t, b, s.robots[t] - best_case.first().unwrap().robots[t] + 1) {
+                    s.robots[t] += 1;
+                    if s.robots[t] == 1 {
+                        first_robot[t] = time;
+                    }
+                }
+            }
+            trace!("{}", s);
+            best_case.push(s);
+        }
+        BestCase {
+            data: best_case,
+            first_robot,
+        }
+    }
+    fn most_geodes(&self, s: &State) -> i32 {
+        for t in [CLAY, OBSIDIAN, GEODE] {
+            if s.robots[t] == 0 {
+                trace!(
+                    "{} {} {}",
+                    self.first_robot[t],
+                    s.tr,
+                    self.first_robot[t] - 1 + s.tr
+                );
+                return self.data
+                    [std::cmp::min(self.data.len() - 1, (self.first_robot[t] + s.tr) as usize)]
+                .resources[GEODE];
+            }
+        }
+        for time in self.data.len() - 1..=0 {
+            if s.robots[GEODE] == self.data[time].robots[GEODE] {
+                return self.data[std::cmp::min(self.data.len() - 1, time + s.tr as usize)].robots
+                    [GEODE];
+            }
+        }
+        self.data.last().unwrap().resources[GEODE]
+    }
+    fn log(&self) {
+        trace!("best case:");
+        State::print_header();
+        for s in &self.data {
+            trace!("{}", s);
+        }
+        trace!("first robots: {:?}", self.first_robot);
+    }
+}
+
+pub type Input = Vec<Blueprint>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut bs = Vec::new();
+    for line in lines(input) {
+        let mut tokens = line.tokens();
+        tokens.expect("Blueprint")?;
+        let id = tokens
+            .next_token("blueprint number")?
+            .strip_suffix(":")?
+            .parse::<i32>("blueprint number")?;
+        expect_words(&mut tokens, "Each ore robot costs")?;
+        let ore_cost = tokens.parse_next::<i32>("ore cost")?;
+        expect_words(&mut tokens, "ore. Each clay robot costs")?;
+        let clay_cost = tokens.parse_next::<i32>("ore cost")?;
+        expect_words(&mut tokens, "ore. Each obsidian robot costs")?;
+        let obs_ore_cost = tokens.parse_next::<i32>("ore cost")?;
+        expect_words(&mut tokens, "ore and")?;
+        let obs_clay_cost = tokens.parse_next::<i32>("clay cost")?;
+        expect_words(&mut tokens, "clay. Each geode robot costs")?;
+        let geode_ore_cost = tokens.parse_next::<i32>("ore cost")?;
+        expect_words(&mut tokens, "ore and")?;
+        let geode_obs_cost = tokens.parse_next::<i32>("obsidian cost")?;
+        expect_words(&mut tokens, "obsidian.")?;
+        tokens.end()?;
+        bs.push(Blueprint {
+            id,
+            ore_cost,
+            clay_cost,
+            obs_ore_cost,
+            obs_clay_cost,
+            geode_ore_cost,
+            geode_obs_cost,
+        });
+    }
+    Ok(bs)
+}
+
+fn expect_words(tokens: &mut Tokens, words: &str) -> Result<(), ParseError> {
+    for word in words.split(' ') {
+        tokens.expect(word)?;
+    }
+    Ok(())
+}
+
+pub fn solve_part1(bs: &Input) -> Answer {
+    let mut quality = 0;
+    for b in bs {
+        let most_geodes = b.find_best(24);
+        debug!("Blueprint {}, Geodes {}", b.id, most_geodes);
+        quality += b.id * most_geodes;
+    }
+    quality.into()
+}
+
+pub fn solve_part2(bs: &Input) -> Answer {
+    let mut prod = 1;
+    for b in bs.iter().take(3) {
+        let most_geodes = b.find_best(32);
+        debug!("Blueprint {}, Geodes {}", b.id, most_geodes);
+        prod *= most_geodes;
+    }
+    prod.into()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
@@ -0,0 +1,20 @@
+use aoc19::{solve_part1, solve_part2, Day19};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day19-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day19>(EXAMPLE)), Answer::from(33));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day19>(EXAMPLE)), Answer::from(3472));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day19>();
+}
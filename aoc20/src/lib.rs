@@ -0,0 +1,118 @@
+use common::parse::lines;
+use common::{Answer, ParseError, Solution};
+use log::{debug, trace};
+
+fn render(f: &[(i64, usize)]) -> String {
+    f.iter()
+        .map(|e| format!("{}", e.0))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn decrypt(f: &[i64], key: i64, cycles: i64) -> Vec<i64> {
+    debug!("Decrypt with key {} and {} cycles", key, cycles);
+    let mut ff = Vec::new();
+    for (i, v) in f.iter().enumerate() {
+        ff.push((v * key, i));
+    }
+    trace!("{}", render(&ff));
+    for cycle in 0..cycles {
+        debug!("Cycle {}", cycle);
+        for i in 0..f.len() {
+            for j in 0..ff.len() {
+                if i == ff[j].1 {
+                    shift(&mut ff, j);
+                    break;
+                }
+            }
+        }
+        trace!("{}", render(&ff));
+    }
+    ff.iter().map(|e| e.0).collect::<Vec<_>>()
+}
+
+fn shift(f: &mut [(i64, usize)], i: usize) {
+    assert!(i < f.len());
+    let m = f.len() - 1;
+    let move_by = f[i].0 % m as i64;
+    trace!("Move {} by {}", f[i].0, move_by);
+    if move_by == 0 {
+        return;
+    }
+    let dest_idx = ((i as i64 + f[i].0) % (m as i64) + (m as i64)) as usize % m;
+    trace!("Move {} from {} to {}", f[i].0, i, dest_idx);
+    if dest_idx > i {
+        for j in i..dest_idx {
+            f.swap(j, j + 1);
+        }
+    } else if dest_idx < i {
+        for j in (dest_idx..i).rev() {
+            f.swap(j, j + 1);
+        }
+    }
+    trace!("{}", render(f));
+}
+
+fn get_coordinates(f: &[i64]) -> i64 {
+    for i in 0..f.len() {
+        if f[i] == 0 {
+            let f1000 = f[(i + 1000) % f.len()];
+            let f2000 = f[(i + 2000) % f.len()];
+            let f3000 = f[(i + 3000) % f.len()];
+            return f1000 + f2000 + f3000;
+        }
+    }
+    panic!();
+}
+
+/// The encrypted file.
+pub type Input = Vec<i64>;
+
+/// The numbers must include exactly one 0, which the coordinates are counted from, and at least
+/// one other number for mixing to move things around.
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut numbers = Vec::new();
+    let mut zero_on = None;
+    for line in lines(input) {
+        let n = line.token().parse::<i64>("integer")?;
+        if n == 0 {
+            if let Some(first) = zero_on {
+                let expected = format!("only one 0 (there's one on line {})", first);
+                return Err(line.token().error(expected));
+            }
+            zero_on = Some(line.number);
+        }
+        numbers.push(n);
+    }
+    if zero_on.is_none() {
+        return Err(ParseError::new("a 0 among the numbers"));
+    }
+    if numbers.len() < 2 {
+        return Err(ParseError::new("at least two numbers"));
+    }
+    Ok(numbers)
+}
+
+pub fn solve_part1(f: &Input) -> Answer {
+    get_coordinates(&decrypt(f, 1, 1)).into()
+}
+
+pub fn solve_part2(f: &Input) -> Answer {
+    get_coordinates(&decrypt(f, 811589153, 10)).into()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
@@ -0,0 +1,35 @@
+use aoc20::{solve_part1, solve_part2, Day20};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day20-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day20>(EXAMPLE)), Answer::from(3));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(
+        solve_part2(&parse::<Day20>(EXAMPLE)),
+        Answer::from(1623178306)
+    );
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day20>();
+}
+
+#[test]
+fn exactly_one_zero_among_at_least_two() {
+    let expected = |input: &str| aoc20::parse(input).unwrap_err().expected;
+    assert_eq!(expected(""), "a 0 among the numbers");
+    assert_eq!(expected("1\n"), "a 0 among the numbers");
+    assert_eq!(expected("0\n"), "at least two numbers");
+    let e = aoc20::parse("1\n0\n2\n0\n").unwrap_err();
+    assert_eq!(e.expected, "only one 0 (there's one on line 2)");
+    assert_eq!(e.location.unwrap().line, 4);
+    assert!(aoc20::parse("0\n-3\n").is_ok());
+}
@@ -0,0 +1,351 @@
+use common::parse::{lines, Token};
+use common::{Answer, ParseError, Solution};
+use log::{debug, trace};
+use std::collections::{HashMap, HashSet, LinkedList};
+use std::fmt;
+
+#[derive(Debug, Clone)]
+enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Operator::Add => "+",
+            Operator::Sub => "-",
+            Operator::Mul => "*",
+            Operator::Div => "/",
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Unknown(String),
+    Known(i64),
+}
+impl Operand {
+    fn simplify(&mut self, hm: &HashMap<String, i64>) -> Option<i64> {
+        match self {
+            Operand::Unknown(name) => match hm.get(name) {
+                Some(val) => {
+                    *self = Operand::Known(*val);
+                    Some(*val)
+                }
+                None => None,
+            },
+            Operand::Known(val) => Some(*val),
+        }
+    }
+}
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Unknown(x) => f.write_str(x),
+            Operand::Known(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Operation {
+    name: String,
+    v1: Operand,
+    op: Operator,
+    v2: Operand,
+}
+impl Operation {
+    fn simplify(&mut self, hm: &mut HashMap<String, i64>) -> Option<i64> {
+        match (self.v1.simplify(hm), self.v2.simplify(hm)) {
+            (Some(m1), Some(m2)) => {
+                let val = match self.op {
+                    Operator::Add => m1 + m2,
+                    Operator::Sub => m1 - m2,
+                    Operator::Mul => m1 * m2,
+                    Operator::Div => m1 / m2,
+                };
+                hm.insert(self.name.clone(), val);
+                Some(val)
+            }
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} {} {}", self.name, self.v1, self.op, self.v2)
+    }
+}
+
+struct Goal {
+    v1: Operand,
+    v2: Operand,
+}
+impl Goal {
+    fn simplify(
+        &mut self,
+        hm: &HashMap<String, i64>,
+        ops: &HashMap<String, Operation>,
+    ) -> Option<i64> {
+        self.v1.simplify(hm);
+        self.v2.simplify(hm);
+        let (mut goal, unknown) = match (&self.v1, &self.v2) {
+            (Operand::Unknown(_), Operand::Unknown(_)) => panic!(),
+            (Operand::Unknown(u), Operand::Known(k)) => (*k, u.clone()),
+            (Operand::Known(k), Operand::Unknown(u)) => (*k, u.clone()),
+            (Operand::Known(_), Operand::Known(_)) => panic!(),
+        };
+        let Some(op) = ops.get(&unknown) else {
+            // root compares humn's number directly.
+            return Some(goal);
+        };
+        let mut op = op.clone();
+        while !hm.contains_key(&op.name) {
+            debug!("{} == {}", op, goal);
+            match (&op.v1, &op.v2) {
+                (Operand::Unknown(_), Operand::Unknown(_)) => panic!(),
+                (Operand::Unknown(u), Operand::Known(k)) => {
+                    match op.op {
+                        Operator::Add => {
+                            goal -= *k;
+                        }
+                        Operator::Sub => {
+                            goal += *k;
+                        }
+                        Operator::Mul => {
+                            goal /= *k;
+                        }
+                        Operator::Div => {
+                            goal *= *k;
+                        }
+                    }
+                    if !ops.contains_key(u) {
+                        debug!("Key not found: {}. Goal is {}", op.name, goal);
+                        return Some(goal);
+                    }
+                    op = ops[u].clone();
+                }
+                (Operand::Known(k), Operand::Unknown(u)) => {
+                    match op.op {
+                        Operator::Add => {
+                            goal -= *k;
+                        }
+                        Operator::Sub => {
+                            goal = *k - goal;
+                        }
+                        Operator::Mul => {
+                            goal /= *k;
+                        }
+                        Operator::Div => panic!(),
+                    }
+                    if !ops.contains_key(u) {
+                        debug!("Key not found: {}. Goal is {}", op.name, goal);
+                        return Some(goal);
+                    }
+                    op = ops[u].clone();
+                }
+                (Operand::Known(_), Operand::Known(_)) => panic!(),
+            }
+        }
+        None
+    }
+}
+
+/// Monkeys that yell a number right away, and monkeys that wait on an operation.
+pub struct Input {
+    happy_monkeys: HashMap<String, i64>,
+    operations: LinkedList<Operation>,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut happy_monkeys = HashMap::new();
+    let mut operations = LinkedList::new();
+    let mut jobs = Vec::new();
+    for line in lines(input) {
+        let (name, job) = line.token().split_once(": ")?;
+        let m = name.text().to_string();
+        let mut tokens = job.split(&[' ']);
+        let first = tokens.next_token("number or monkey name")?;
+        match tokens.next() {
+            None => {
+                if m == "root" {
+                    return Err(first.error("an operation (root compares two monkeys' numbers)"));
+                }
+                happy_monkeys.insert(m, first.parse::<i64>("number")?);
+            }
+            Some(op) => {
+                let op = match op.text() {
+                    "+" => Operator::Add,
+                    "-" => Operator::Sub,
+                    "*" => Operator::Mul,
+                    "/" => Operator::Div,
+                    _ => return Err(op.error("operator (+, -, * or /)")),
+                };
+                let m2 = tokens.next_token("monkey name")?;
+                tokens.end()?;
+                if m == "humn" {
+                    return Err(first.error("a number (humn is you)"));
+                }
+                jobs.push((name, [first, m2], op.clone()));
+                operations.push_back(Operation {
+                    name: m,
+                    v1: Operand::Unknown(first.text().to_string()),
+                    op,
+                    v2: Operand::Unknown(m2.text().to_string()),
+                });
+            }
+        }
+    }
+    check_jobs(&happy_monkeys, &jobs)?;
+    Ok(Input {
+        happy_monkeys,
+        operations,
+    })
+}
+
+/// Checks that every monkey's number can be worked out: each operand names a monkey that has a
+/// job, `root` and `humn` are among them, and no monkey ends up waiting on itself. Part 2 also
+/// needs `humn`'s number to reach `root` along a single chain of jobs it can undo. `jobs` holds
+/// each waiting monkey's name, its two operands and its operator.
+fn check_jobs(
+    numbers: &HashMap<String, i64>,
+    jobs: &[(Token, [Token; 2], Operator)],
+) -> Result<(), ParseError> {
+    let names = jobs
+        .iter()
+        .map(|(name, ..)| name.text())
+        .collect::<Vec<_>>();
+    let waiting = names.iter().copied().collect::<HashSet<_>>();
+    let has_job = |name: &str| numbers.contains_key(name) || waiting.contains(name);
+    for (_, operands, _) in jobs {
+        if let Some(missing) = operands.iter().find(|operand| !has_job(operand.text())) {
+            return Err(missing.error("the name of a monkey with a job"));
+        }
+    }
+    if !has_job("root") {
+        return Err(ParseError::new("a monkey named root"));
+    }
+    if !numbers.contains_key("humn") {
+        return Err(ParseError::new("a monkey named humn"));
+    }
+    // Work out which jobs can be done, in dependency order; any left over wait on a cycle.
+    let mut pending = vec![0; jobs.len()];
+    let mut dependents = HashMap::<&str, Vec<usize>>::new();
+    for (i, (_, operands, _)) in jobs.iter().enumerate() {
+        for operand in operands.iter().filter(|o| !numbers.contains_key(o.text())) {
+            pending[i] += 1;
+            dependents.entry(operand.text()).or_default().push(i);
+        }
+    }
+    let mut ready = (0..jobs.len())
+        .filter(|&i| pending[i] == 0)
+        .collect::<Vec<_>>();
+    let mut order = Vec::with_capacity(jobs.len());
+    while let Some(i) = ready.pop() {
+        order.push(i);
+        for &d in dependents.get(names[i]).into_iter().flatten() {
+            pending[d] -= 1;
+            if pending[d] == 0 {
+                ready.push(d);
+            }
+        }
+    }
+    if let Some(i) = pending.iter().position(|&p| p > 0) {
+        return Err(jobs[i]
+            .0
+            .error("a job that can be worked out (it waits on a monkey that waits on it)"));
+    }
+    let mut uses_humn = HashSet::from(["humn"]);
+    for &i in &order {
+        if jobs[i].1.iter().any(|o| uses_humn.contains(o.text())) {
+            uses_humn.insert(names[i]);
+        }
+    }
+    let index = names
+        .iter()
+        .enumerate()
+        .map(|(i, &name)| (name, i))
+        .collect::<HashMap<_, _>>();
+    let mut name = "root";
+    while name != "humn" {
+        let (job, [left, right], op) = &jobs[index[name]];
+        let next =
+            match (
+                uses_humn.contains(left.text()),
+                uses_humn.contains(right.text()),
+            ) {
+                (false, false) => return Err(job.error("a job that depends on humn's number")),
+                (true, true) => return Err(right.error(
+                    "a monkey whose number doesn't depend on humn (the other side already does)",
+                )),
+                (false, true) if name != "root" && matches!(op, Operator::Div) => {
+                    return Err(right.error("a divisor that doesn't depend on humn"))
+                }
+                (true, false) => left,
+                (false, true) => right,
+            };
+        name = next.text();
+    }
+    Ok(())
+}
+
+pub fn solve_part1(input: &Input) -> Answer {
+    let mut happy_monkeys = input.happy_monkeys.clone();
+    let mut operations = input.operations.clone();
+    trace!("{:?}", happy_monkeys);
+    trace!("{:?}", operations);
+    while let Some(mut o) = operations.pop_front() {
+        if o.simplify(&mut happy_monkeys).is_none() {
+            operations.push_back(o);
+        }
+    }
+    happy_monkeys["root"].into()
+}
+
+pub fn solve_part2(input: &Input) -> Answer {
+    let mut happy_monkeys = input.happy_monkeys.clone();
+    happy_monkeys.remove("humn");
+    let mut ops = HashMap::new();
+    let mut goals = LinkedList::new();
+    for op in &input.operations {
+        if op.name == "root" {
+            goals.push_back(Goal {
+                v1: op.v1.clone(),
+                v2: op.v2.clone(),
+            });
+        } else {
+            ops.insert(op.name.clone(), op.clone());
+        }
+    }
+    loop {
+        let num_known = happy_monkeys.len();
+        ops.retain(|_, v| v.simplify(&mut happy_monkeys).is_none());
+        if num_known == happy_monkeys.len() {
+            break;
+        }
+    }
+    let mut humn = None;
+    for goal in &mut goals {
+        humn = goal.simplify(&happy_monkeys, &ops);
+    }
+    humn.unwrap().into()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
@@ -0,0 +1,74 @@
+use aoc21::{solve_part1, solve_part2, Day21};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day21-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day21>(EXAMPLE)), Answer::from(152));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day21>(EXAMPLE)), Answer::from(301));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day21>();
+}
+
+#[test]
+fn every_monkey_must_be_worked_out() {
+    let error = |input: &str| aoc21::parse(input).err().unwrap();
+    let first_line = EXAMPLE.lines().next().unwrap();
+    let e = error(first_line);
+    assert_eq!(e.expected, "the name of a monkey with a job");
+    assert_eq!(e.location.unwrap().line, 1);
+
+    let e = error("abcd: 1\nefgh: abcd + xyzw\n");
+    let location = e.location.unwrap();
+    assert_eq!((location.line, location.column), (2, 14));
+
+    assert_eq!(error("abcd: 1\n").expected, "a monkey named root");
+
+    let e = error("root: abcd + efgh\nabcd: efgh * efgh\nefgh: abcd - ijkl\nijkl: 4\nhumn: 1\n");
+    assert!(e.expected.starts_with("a job that can be worked out"));
+    assert!(aoc21::parse("root: abcd + efgh\nabcd: 4\nefgh: humn * abcd\nhumn: 1\n").is_ok());
+}
+
+#[test]
+fn humn_must_be_solvable_for() {
+    let error = |input: &str| aoc21::parse(input).err().unwrap();
+    let e = error("root: 5\nhumn: 1\n");
+    assert_eq!(
+        e.expected,
+        "an operation (root compares two monkeys' numbers)"
+    );
+    assert_eq!(e.location.unwrap().column, 7);
+    assert_eq!(
+        error("root: abcd + efgh\nabcd: 1\nefgh: 2\n").expected,
+        "a monkey named humn"
+    );
+    let e = error("root: abcd + humn\nabcd: 1\nhumn: abcd * abcd\n");
+    assert_eq!(e.expected, "a number (humn is you)");
+    assert_eq!(e.location.unwrap().line, 3);
+
+    let e = error("root: abcd + efgh\nabcd: 1\nefgh: 2\nhumn: 3\n");
+    assert_eq!(e.expected, "a job that depends on humn's number");
+    assert_eq!(e.location.unwrap().line, 1);
+    let e = error("root: abcd + efgh\nabcd: humn * humn\nefgh: 2\nhumn: 3\n");
+    assert!(e
+        .expected
+        .starts_with("a monkey whose number doesn't depend on humn"));
+    let location = e.location.unwrap();
+    assert_eq!((location.line, location.column), (2, 14));
+    let e = error("root: abcd + efgh\nabcd: efgh / humn\nefgh: 2\nhumn: 3\n");
+    assert_eq!(e.expected, "a divisor that doesn't depend on humn");
+    assert_eq!(e.location.unwrap().line, 2);
+
+    // Nothing else is needed for part 2 to work back from root's equation to humn.
+    let input = aoc21::parse("root: humn / abcd\nabcd: 6\nhumn: 3\n").unwrap();
+    assert_eq!(aoc21::solve_part2(&input), 6.into());
+}
@@ -0,0 +1,28 @@
+use aoc22::{solve_part1, solve_part2, Day22};
+use common::testing::{check_real_input, parse};
+use common::{Answer, Day};
+
+const EXAMPLE: &str = include_str!("../../examples/day22-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day22>(EXAMPLE)), Answer::from(6032));
+}
+
+#[ignore = "cube edges are only wired up for the 50x50 faces of the real input"]
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day22>(EXAMPLE)), Answer::from(5031));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day22>();
+}
+
+#[test]
+fn runner_flags_the_example_cube() {
+    let answers = Day22.run_example(EXAMPLE).unwrap();
+    assert_eq!(answers.part1, Answer::from(6032));
+    assert!(matches!(answers.part2, Answer::Text(_)));
+}
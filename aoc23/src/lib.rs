@@ -0,0 +1,166 @@
+use common::grid::{Grid, Point, SparseGrid};
+use common::{Answer, ParseError, Solution};
+use core::fmt;
+use log::{debug, trace};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone)]
+struct Elf;
+impl fmt::Display for Elf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("#")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    elves: SparseGrid<Elf>,
+}
+impl Map {
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        let map = Grid::parse(input, "`#` or `.`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        let elves = map
+            .iter()
+            .filter(|(_, &elf)| elf)
+            .map(|(p, _)| (p, Elf))
+            .collect();
+        Ok(Map { elves })
+    }
+    fn has_neighbor(&self, pos: Point) -> bool {
+        assert!(self.elves.contains(pos));
+        pos.neighbors8().any(|n| self.elves.contains(n))
+    }
+    /// The map with the elf at `pos` marked with `@`.
+    fn render_with_annotation(&self, pos: Point) -> String {
+        self.elves.render(|p, elf| {
+            if p == pos {
+                '@'
+            } else if elf.is_some() {
+                '#'
+            } else {
+                '.'
+            }
+        })
+    }
+    /// The number of empty tiles in the smallest rectangle holding every elf.
+    fn empty_ground(&self) -> i32 {
+        let (min, max) = self.elves.bounds().unwrap_or_default();
+        (max.x - min.x + 1) * (max.y - min.y + 1) - self.elves.len() as i32
+    }
+    /// Runs up to `max_rounds` rounds, returning the number of rounds run.
+    fn execute(&mut self, max_rounds: Option<usize>) -> usize {
+        trace!("Starting position");
+        trace!("{}", self);
+        let max_rounds = max_rounds.unwrap_or(usize::MAX);
+        for round in 0..max_rounds {
+            let mut proposals = HashMap::new();
+            let mut invalid_proposals = HashSet::new();
+            for elf in self.elves.points() {
+                trace!("\n{}", self.render_with_annotation(elf));
+                if !self.has_neighbor(elf) {
+                    trace!("Has no neighbors");
+                    continue;
+                }
+                for rr in round..(round + 4) {
+                    let (delta, check) = [
+                        (
+                            Point::UP,
+                            [Point::new(-1, -1), Point::new(0, -1), Point::new(1, -1)],
+                        ),
+                        (
+                            Point::DOWN,
+                            [Point::new(-1, 1), Point::new(0, 1), Point::new(1, 1)],
+                        ),
+                        (
+                            Point::LEFT,
+                            [Point::new(-1, -1), Point::new(-1, 0), Point::new(-1, 1)],
+                        ),
+                        (
+                            Point::RIGHT,
+                            [Point::new(1, -1), Point::new(1, 0), Point::new(1, 1)],
+                        ),
+                    ][rr % 4];
+                    let mut all_empty = true;
+                    let to = elf + delta;
+                    for c in check {
+                        if self.elves.contains(elf + c) {
+                            all_empty = false;
+                            break;
+                        }
+                    }
+                    if all_empty {
+                        trace!("Can move in direction {}", delta);
+                        if !invalid_proposals.contains(&to) {
+                            trace!("No conflict at {}", to);
+                            assert!(proposals.insert(to, elf).is_none());
+                            assert!(invalid_proposals.insert(to));
+                        } else {
+                            trace!("Conflict at {}", to);
+                            proposals.remove(&to);
+                        }
+                        break;
+                    } else {
+                        trace!("Can't move in direction {}", delta);
+                    }
+                }
+            }
+            if proposals.is_empty() {
+                debug!("No changes after {} rounds", round + 1);
+                return round + 1;
+            }
+            for (to, from) in proposals {
+                trace!("Move from {} to {}", from, to);
+                assert!(self.elves.remove(from).is_some(), "No elf at {}", from);
+                assert!(
+                    self.elves.insert(to, Elf).is_none(),
+                    "Unexpected elf at {}",
+                    to
+                );
+            }
+            trace!("Round {}", round + 1);
+            trace!("{}", self);
+        }
+        max_rounds
+    }
+}
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.elves.fmt(f)
+    }
+}
+
+pub type Input = Map;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    Map::parse(input)
+}
+
+pub fn solve_part1(m: &Input) -> Answer {
+    let mut m = m.clone();
+    m.execute(Some(10));
+    m.empty_ground().into()
+}
+
+pub fn solve_part2(m: &Input) -> Answer {
+    m.clone().execute(None).into()
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
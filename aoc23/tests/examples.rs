@@ -0,0 +1,20 @@
+use aoc23::{solve_part1, solve_part2, Day23};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day23-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day23>(EXAMPLE)), Answer::from(110));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day23>(EXAMPLE)), Answer::from(20));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day23>();
+}
@@ -0,0 +1,231 @@
+/*
+
+* There are never any up/down blizzards in the first/last column that could "escape".
+*
+
+*/
+
+use common::grid::{Grid, Point};
+use common::parse::lines;
+use common::search::{a_star, Heuristic, Successors};
+use common::{Answer, ParseError, Solution};
+use log::trace;
+
+#[allow(dead_code)]
+const STAY: u8 = 0;
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 3;
+const RIGHT: u8 = 4;
+
+#[derive(Clone)]
+pub struct Valley {
+    map: Grid<Vec<u8>>,
+}
+impl Valley {
+    /// The map must be walled in all round, with one gap at the left of the top wall and one at
+    /// the right of the bottom wall, and no walls inside.
+    fn parse(input: &str) -> Result<Valley, ParseError> {
+        let lines = lines(input).collect::<Vec<_>>();
+        let tiles = Grid::from_lines(
+            lines.iter().copied(),
+            "map tile (`#`, `.`, `^`, `v`, `<` or `>`)",
+            |c| "#.^v<>".contains(c).then_some(c),
+        )?;
+        if tiles.width() < 3 || tiles.height() < 3 {
+            return Err(lines[0].error_at(1, "a map at least 3 tiles wide and high"));
+        }
+        let map = tiles.map(|_, &c| match c {
+            '^' => vec![UP],
+            'v' => vec![DOWN],
+            '<' => vec![LEFT],
+            '>' => vec![RIGHT],
+            _ => vec![],
+        });
+        let valley = Valley { map };
+        let end = tiles.end();
+        for (p, &c) in tiles.iter() {
+            let expected = if valley.is_wall(p) {
+                (c != '#').then_some("wall (`#`)")
+            } else if p.y == 0 || p.y == end.y {
+                (c != '.').then_some("gap in the wall (`.`)")
+            } else {
+                (c == '#').then_some("open ground or a blizzard (`.`, `^`, `v`, `<` or `>`)")
+            };
+            if let Some(expected) = expected {
+                return Err(lines[p.y as usize].error_at(p.x as usize + 1, expected));
+            }
+        }
+        Ok(valley)
+    }
+    fn render(&self) -> String {
+        self.map.render(|p, blizzards| {
+            if self.is_wall(p) {
+                '#'
+            } else if blizzards.is_empty() {
+                '.'
+            } else if blizzards.len() == 1 {
+                match blizzards[0] {
+                    UP => '^',
+                    DOWN => 'v',
+                    LEFT => '<',
+                    RIGHT => '>',
+                    _ => panic!(),
+                }
+            } else {
+                char::from_digit(blizzards.len() as u32, 10).unwrap_or('*')
+            }
+        })
+    }
+    fn is_wall(&self, p: Point) -> bool {
+        let end = self.map.end();
+        p.x == 0 || p.x == end.x || p.y == 0 && p.x != 1 || p.y == end.y && p.x != end.x - 1
+    }
+    fn advance(&mut self) {
+        let end = self.map.end();
+        let mut next_map = self.map.map(|_, _| vec![]);
+        for (p, blizzards) in self.map.iter() {
+            for b in blizzards {
+                let next = match *b {
+                    UP => Point::new(p.x, if p.y == 1 { end.y - 1 } else { p.y - 1 }),
+                    DOWN => Point::new(p.x, if p.y == end.y - 1 { 1 } else { p.y + 1 }),
+                    LEFT => Point::new(if p.x == 1 { end.x - 1 } else { p.x - 1 }, p.y),
+                    RIGHT => Point::new(if p.x == end.x - 1 { 1 } else { p.x + 1 }, p.y),
+                    _ => panic!(),
+                };
+                next_map[next].push(*b);
+            }
+        }
+        self.map = next_map;
+    }
+}
+
+pub struct ValleyState {
+    open: Vec<Grid<bool>>,
+}
+impl ValleyState {
+    fn from(v: &Valley) -> ValleyState {
+        let mut v = v.clone();
+        let mut open = Vec::new();
+        for _ in 0..v.map.width() * v.map.height() {
+            open.push(
+                v.map
+                    .map(|p, blizzards| blizzards.is_empty() && !v.is_wall(p)),
+            );
+            v.advance();
+        }
+        ValleyState { open }
+    }
+    fn map(&self) -> &Grid<bool> {
+        &self.open[0]
+    }
+    /// Where the expedition sets off: the gap in the top wall.
+    fn entrance(&self) -> Point {
+        Point::new(1, 0)
+    }
+    /// The gap in the bottom wall.
+    fn exit(&self) -> Point {
+        self.map().end() + Point::LEFT
+    }
+    /// Points outside the valley are never open.
+    fn is_open(&self, t: usize, p: Point) -> bool {
+        self.open[t % self.open.len()].get(p) == Some(&true)
+    }
+    #[allow(dead_code)]
+    fn render(&self, t: usize) -> String {
+        self.map()
+            .render(|p, _| if self.is_open(t, p) { '.' } else { '#' })
+    }
+    /// The valley at minute `t` with the expedition at `e`.
+    fn render_with_expedition(&self, t: usize, e: Point) -> String {
+        self.map().render(|p, _| {
+            if p == e {
+                'E'
+            } else if self.is_open(t, p) {
+                '.'
+            } else {
+                '#'
+            }
+        })
+    }
+    /// The earliest minute the expedition can reach `goal`, setting off from `start` at minute
+    /// `start_t`, or `None` if the blizzards always block the way.
+    fn cross(&self, start_t: usize, start: Point, goal: Point) -> Option<usize> {
+        let trip = Trip {
+            vs: self,
+            start_t,
+            goal,
+        };
+        let found = a_star(&trip, [(start, 0)], |&(p, _)| p == goal)?;
+        for (steps, (p, _)) in found.path.iter().enumerate() {
+            let t = start_t + steps;
+            trace!("minute {}:\n{}", t, self.render_with_expedition(t, *p));
+        }
+        Some(start_t + found.value)
+    }
+}
+
+/// One crossing of the valley. States are the expedition's position and how many minutes it has
+/// been walking; since the blizzards repeat, the minutes only count up to one cycle.
+struct Trip<'a> {
+    vs: &'a ValleyState,
+    start_t: usize,
+    goal: Point,
+}
+impl Successors for Trip<'_> {
+    type State = (Point, usize);
+    /// Waiting or moving, wherever there won't be a blizzard next minute.
+    fn successors(&self, &(p, steps): &(Point, usize)) -> Vec<((Point, usize), usize)> {
+        let t = self.start_t + steps + 1;
+        std::iter::once(p)
+            .chain(p.neighbors4())
+            .filter(|&next| self.vs.is_open(t, next))
+            .map(|next| ((next, (steps + 1) % self.vs.open.len()), 1))
+            .collect()
+    }
+}
+impl Heuristic for Trip<'_> {
+    fn estimate(&self, &(p, _): &(Point, usize)) -> usize {
+        p.manhattan(self.goal) as usize
+    }
+}
+
+/// Which cells of the valley are open at each minute of the blizzards' cycle.
+pub type Input = ValleyState;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let valley = Valley::parse(input)?;
+    trace!("\n{}", valley.render());
+    Ok(ValleyState::from(&valley))
+}
+
+/// The minute the last crossing ends, or says the blizzards never let the expedition through.
+fn answer(t: Option<usize>) -> Answer {
+    t.map_or_else(|| Answer::Text("no route".to_string()), Answer::from)
+}
+
+pub fn solve_part1(vs: &Input) -> Answer {
+    answer(vs.cross(0, vs.entrance(), vs.exit()))
+}
+
+pub fn solve_part2(vs: &Input) -> Answer {
+    let there = |t| vs.cross(t, vs.entrance(), vs.exit());
+    let back = |t| vs.cross(t, vs.exit(), vs.entrance());
+    answer(there(0).and_then(back).and_then(there))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
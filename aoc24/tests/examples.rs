@@ -0,0 +1,55 @@
+use aoc24::{solve_part1, solve_part2, Day24};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day24-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(solve_part1(&parse::<Day24>(EXAMPLE)), Answer::from(18));
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day24>(EXAMPLE)), Answer::from(54));
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day24>();
+}
+
+#[test]
+fn valley_must_be_walled_in() {
+    let error = |input: &str| {
+        let e = aoc24::parse(input).err().unwrap();
+        let location = e.location.unwrap();
+        (location.line, location.column, e.expected)
+    };
+    assert_eq!(aoc24::parse("").err().unwrap().expected, "a map");
+    assert_eq!(error("\n").2, "a map at least 3 tiles wide and high");
+    assert_eq!(
+        error("#.#\n#.#\n").2,
+        "a map at least 3 tiles wide and high"
+    );
+    assert!(aoc24::parse("#.##\n#..#\n##.#\n").is_ok());
+    assert_eq!(
+        error("##.#\n#..#\n##.#\n"),
+        (1, 2, "gap in the wall (`.`)".into())
+    );
+    assert_eq!(error("#..#\n#..#\n##.#\n"), (1, 3, "wall (`#`)".into()));
+    assert_eq!(error("#.##\n...#\n##.#\n"), (2, 1, "wall (`#`)".into()));
+    assert_eq!(error("#.##\n#.##\n##.#\n").0, 2);
+    assert_eq!(
+        error("#.##\n#..#\n###.\n"),
+        (3, 3, "gap in the wall (`.`)".into())
+    );
+}
+
+#[test]
+fn blizzards_that_never_clear() {
+    // Both tiles of a one-wide valley always hold a blizzard.
+    let vs = parse::<Day24>("#.#\n#v#\n#v#\n#.#\n");
+    assert_eq!(solve_part1(&vs), Answer::Text("no route".into()));
+    assert_eq!(solve_part2(&vs), Answer::Text("no route".into()));
+}
@@ -0,0 +1,129 @@
+use common::parse::lines;
+use common::{Answer, ParseError, Solution};
+use log::trace;
+
+struct Snafu(i64);
+impl Snafu {
+    fn parse(s: &str) -> Snafu {
+        let mut x: i64 = 0;
+        let mut place = 1;
+        for c in s.chars().rev() {
+            x += digit_to_val(c) * place;
+            place *= 5;
+        }
+        Snafu(x)
+    }
+    fn encode(&self) -> String {
+        let mut digits = Vec::new();
+        let mut remaining = self.0;
+        let divisor = 5;
+        while remaining > 0 {
+            digits.push(remaining % divisor);
+            remaining /= 5;
+        }
+        digits.push(0);
+        trace!("{:?}", digits);
+        let mut chars = Vec::new();
+        for i in 0..(digits.len() - 1) {
+            while digits[i] >= 5 {
+                digits[i] -= 5;
+                digits[i + 1] += 1;
+            }
+            match digits[i] {
+                0 => {
+                    chars.push("0");
+                }
+                1 => {
+                    chars.push("1");
+                }
+                2 => {
+                    chars.push("2");
+                }
+                3 => {
+                    chars.push("=");
+                    digits[i + 1] += 1;
+                }
+                4 => {
+                    chars.push("-");
+                    digits[i + 1] += 1;
+                }
+                _ => panic!(),
+            }
+        }
+        match digits[digits.len() - 1] {
+            0 => {}
+            1 => {
+                chars.push("1");
+            }
+            2 => {
+                chars.push("2");
+            }
+            _ => panic!(),
+        }
+        chars.reverse();
+        chars.join("")
+    }
+}
+
+fn digit_to_val(c: char) -> i64 {
+    match c {
+        '=' => -2,
+        '-' => -1,
+        '0' => 0,
+        '1' => 1,
+        '2' => 2,
+        _ => panic!(),
+    }
+}
+
+/// The fuel requirements, in SNAFU.
+pub type Input = Vec<String>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut numbers = Vec::new();
+    for line in lines(input) {
+        if line.is_empty() {
+            return Err(line.error_at_end("SNAFU number"));
+        }
+        for (column, c) in line.chars() {
+            if !matches!(c, '2' | '1' | '0' | '-' | '=') {
+                return Err(line.error_at(column, "SNAFU digit (2, 1, 0, - or =)"));
+            }
+        }
+        numbers.push(line.text.to_string());
+    }
+    Ok(numbers)
+}
+
+pub fn solve_part1(lines: &Input) -> Answer {
+    let mut total = Snafu(0);
+    for line in lines {
+        let x = Snafu::parse(line);
+        let rt = x.encode();
+        trace!("{} = {} = {}", line, x.0, rt);
+        assert!(*line == rt);
+        total.0 += x.0;
+    }
+    total.encode().into()
+}
+
+/// There is no second puzzle on the last day.
+pub fn solve_part2(_lines: &Input) -> Answer {
+    Answer::None
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+    type Input = Input;
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input)
+    }
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input)
+    }
+}
@@ -0,0 +1,23 @@
+use aoc25::{solve_part1, solve_part2, Day25};
+use common::testing::{check_real_input, parse};
+use common::Answer;
+
+const EXAMPLE: &str = include_str!("../../examples/day25-example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(
+        solve_part1(&parse::<Day25>(EXAMPLE)),
+        Answer::from("2=-1=0".to_string())
+    );
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(solve_part2(&parse::<Day25>(EXAMPLE)), Answer::None);
+}
+
+#[test]
+fn real_input() {
+    check_real_input::<Day25>();
+}
@@ -0,0 +1,397 @@
+//! 2D grids for the map-shaped puzzles. `Grid` stores every cell of a rectangle, which may sit
+//! anywhere in the plane; `SparseGrid` stores only the occupied cells of an unbounded plane.
+//!
+//! Coordinates are signed and follow the puzzles' screen convention: `x` grows to the right and
+//! `y` grows downwards, so `Point::UP` is `(0, -1)`.
+
+use crate::parse::{lines, Line};
+use crate::ParseError;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+impl Point {
+    pub const UP: Point = Point::new(0, -1);
+    pub const DOWN: Point = Point::new(0, 1);
+    pub const LEFT: Point = Point::new(-1, 0);
+    pub const RIGHT: Point = Point::new(1, 0);
+    /// The four orthogonal directions, clockwise from up.
+    pub const DIRECTIONS4: [Point; 4] = [Point::UP, Point::RIGHT, Point::DOWN, Point::LEFT];
+    /// The eight orthogonal and diagonal directions, clockwise from up.
+    pub const DIRECTIONS8: [Point; 8] = [
+        Point::new(0, -1),
+        Point::new(1, -1),
+        Point::new(1, 0),
+        Point::new(1, 1),
+        Point::new(0, 1),
+        Point::new(-1, 1),
+        Point::new(-1, 0),
+        Point::new(-1, -1),
+    ];
+
+    pub const fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+    /// The four orthogonally adjacent points, with no bounds to stop at.
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Point::DIRECTIONS4.into_iter().map(move |d| self + d)
+    }
+    /// The eight adjacent points including diagonals, with no bounds to stop at.
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        Point::DIRECTIONS8.into_iter().map(move |d| self + d)
+    }
+    pub fn manhattan(self, other: Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+impl Add for Point {
+    type Output = Point;
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+impl Sub for Point {
+    type Output = Point;
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+impl Mul<i32> for Point {
+    type Output = Point;
+    fn mul(self, n: i32) -> Point {
+        Point::new(self.x * n, self.y * n)
+    }
+}
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// A dense rectangle of cells. The top-left cell is at `origin`, which is `(0, 0)` unless the
+/// grid was made with `with_origin`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    origin: Point,
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid::with_origin(Point::default(), width, height, fill)
+    }
+    pub fn with_origin(origin: Point, width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            origin,
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+    /// Like `from_lines`, but rows shorter than the longest one are padded with `pad` instead of
+    /// being an error, for maps whose trailing blanks were trimmed.
+    pub fn from_lines_padded<'a>(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        pad: T,
+        expected: &str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let rows = parse_rows(lines, expected, cell)?;
+        let width = rows.iter().map(|(_, row)| row.len()).max().unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (_, mut row) in rows {
+            row.resize(width, pad.clone());
+            cells.append(&mut row);
+        }
+        Ok(Grid {
+            origin: Point::default(),
+            width,
+            height,
+            cells,
+        })
+    }
+}
+impl<T> Grid<T> {
+    /// Parses a character map, one row per line, converting each character with `cell`. A
+    /// character that `cell` rejects is reported as not being what was `expected`, and every row
+    /// must be the same length.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        Grid::from_lines(lines(input), expected, cell)
+    }
+    /// Like `parse`, for when the map is only part of the input.
+    pub fn from_lines<'a>(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        expected: &str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let rows = parse_rows(lines, expected, cell)?;
+        let width = rows[0].1.len();
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (line, mut row) in rows {
+            if row.len() < width {
+                let expected = format!("{} (rows are {} wide)", expected, width);
+                return Err(line.error_at_end(expected));
+            } else if row.len() > width {
+                let expected = format!("end of row (rows are {} wide)", width);
+                return Err(line.error_at(width + 1, expected));
+            }
+            cells.append(&mut row);
+        }
+        Ok(Grid {
+            origin: Point::default(),
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    /// The top-left corner.
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+    /// The bottom-right corner.
+    pub fn end(&self) -> Point {
+        self.origin + Point::new(self.width as i32 - 1, self.height as i32 - 1)
+    }
+    fn index_of(&self, p: Point) -> Option<usize> {
+        let x = usize::try_from(p.x - self.origin.x).ok()?;
+        let y = usize::try_from(p.y - self.origin.y).ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+    pub fn contains(&self, p: Point) -> bool {
+        self.index_of(p).is_some()
+    }
+    /// The cell at `p`, or `None` outside the grid.
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (origin, width, height) = (self.origin, self.width as i32, self.height as i32);
+        (0..height).flat_map(move |y| (0..width).map(move |x| origin + Point::new(x, y)))
+    }
+    /// Every cell along with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+    /// The first point, row by row, whose cell matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(p, _)| p)
+    }
+    /// The orthogonal neighbors of `p` that are inside the grid.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors4().filter(|&n| self.contains(n))
+    }
+    /// The orthogonal and diagonal neighbors of `p` that are inside the grid.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors8().filter(|&n| self.contains(n))
+    }
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, mut f: impl FnMut(Point, &T) -> U) -> Grid<U> {
+        Grid {
+            origin: self.origin,
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(p, cell)| f(p, cell)).collect(),
+        }
+    }
+    /// Draws the grid with one character per cell, e.g. to overlay something that isn't stored
+    /// in the cells. Every row ends with a newline.
+    pub fn render(&self, mut f: impl FnMut(Point, &T) -> char) -> String {
+        let mut s = String::with_capacity((self.width + 1) * self.height);
+        for (p, cell) in self.iter() {
+            s.push(f(p, cell));
+            if p.x == self.end().x {
+                s.push('\n');
+            }
+        }
+        s
+    }
+}
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+    fn index(&self, p: Point) -> &T {
+        match self.index_of(p) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "{} is outside the grid from {} to {}",
+                p,
+                self.origin,
+                self.end()
+            ),
+        }
+    }
+}
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        match self.index_of(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "{} is outside the grid from {} to {}",
+                p,
+                self.origin,
+                self.end()
+            ),
+        }
+    }
+}
+/// One row per line, each cell written with its own `Display`.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (p, cell) in self.iter() {
+            write!(f, "{}", cell)?;
+            if p.x == self.end().x {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The cells of each line, along with the line for reporting errors about its length.
+fn parse_rows<'a, T>(
+    lines: impl IntoIterator<Item = Line<'a>>,
+    expected: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<(Line<'a>, Vec<T>)>, ParseError> {
+    let mut rows = Vec::new();
+    for line in lines {
+        let mut row = Vec::new();
+        for (column, c) in line.chars() {
+            row.push(cell(c).ok_or_else(|| line.error_at(column, expected))?);
+        }
+        rows.push((line, row));
+    }
+    if rows.is_empty() {
+        return Err(ParseError::new("a map"));
+    }
+    Ok(rows)
+}
+
+/// The occupied cells of an unbounded plane, for things that spread out, like the elves on day
+/// 23. Its extent is always just the bounding box of what's in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+    pub fn contains(&self, p: Point) -> bool {
+        self.cells.contains_key(&p)
+    }
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.cells.get(&p)
+    }
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+    /// Sets the cell at `p`, returning what was there before.
+    pub fn insert(&mut self, p: Point, value: T) -> Option<T> {
+        self.cells.insert(p, value)
+    }
+    pub fn remove(&mut self, p: Point) -> Option<T> {
+        self.cells.remove(&p)
+    }
+    /// The occupied points, in no particular order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(p, cell)| (*p, cell))
+    }
+    /// The top-left and bottom-right corners of the smallest rectangle holding every occupied
+    /// cell, or `None` if there are none.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.points();
+        let first = points.next()?;
+        Some(points.fold((first, first), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        }))
+    }
+    /// Draws the bounding box with one character per cell; `f` gets `None` for empty cells.
+    /// Every row ends with a newline.
+    pub fn render(&self, mut f: impl FnMut(Point, Option<&T>) -> char) -> String {
+        let mut s = String::new();
+        if let Some((min, max)) = self.bounds() {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    let p = Point::new(x, y);
+                    s.push(f(p, self.get(p)));
+                }
+                s.push('\n');
+            }
+        }
+        s
+    }
+}
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> SparseGrid<T> {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+/// The bounding box, one row per line, with occupied cells written with their own `Display` and
+/// empty ones as `.`.
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((min, max)) = self.bounds() {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    match self.get(Point::new(x, y)) {
+                        Some(cell) => write!(f, "{}", cell)?,
+                        None => f.write_str(".")?,
+                    }
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+//! Finding puzzle inputs on disk.
+//!
+//! Real inputs are personal and git-ignored, so they live in a directory of their own:
+//! `inputs/dayNN.txt` by default, or wherever `AOC_INPUT_DIR` points, e.g. a cache shared by the
+//! whole team. The examples from the puzzle text are checked in as `examples/dayNN-NAME.txt`.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Overrides where real inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The directory holding real inputs: `$AOC_INPUT_DIR` if it's set and not empty, otherwise
+/// `default`.
+pub fn input_dir(default: impl Into<PathBuf>) -> PathBuf {
+    match std::env::var_os(INPUT_DIR_VAR) {
+        Some(dir) if !dir.is_empty() => dir.into(),
+        _ => default.into(),
+    }
+}
+
+/// The real input for `day` in `dir`.
+pub fn input_path(dir: &Path, day: u32) -> PathBuf {
+    dir.join(format!("day{:02}.txt", day))
+}
+
+/// Every example for `day` in `dir`, sorted by name.
+pub fn example_paths(dir: &Path, day: u32) -> io::Result<Vec<PathBuf>> {
+    let prefix = format!("day{:02}-", day);
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_example = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".txt"));
+        if is_example {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
@@ -0,0 +1,196 @@
+//! Closed intervals of integers, and sets of them kept as sorted, disjoint intervals. Useful
+//! whenever the puzzle is about ranges too large to enumerate: section assignments (day 4),
+//! sensor coverage along a row (day 15), and so on.
+
+use std::fmt;
+
+/// The integers from `start` to `end`, both included. Never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    start: i64,
+    end: i64,
+}
+
+impl Interval {
+    /// `None` if `end` is before `start`.
+    pub fn new(start: i64, end: i64) -> Option<Interval> {
+        (start <= end).then_some(Interval { start, end })
+    }
+    pub fn point(x: i64) -> Interval {
+        Interval { start: x, end: x }
+    }
+    pub fn start(&self) -> i64 {
+        self.start
+    }
+    pub fn end(&self) -> i64 {
+        self.end
+    }
+    /// How many integers the interval holds. Always at least 1, so there's no `is_empty`; a
+    /// `u128`, since all of `i64` holds one more than `u64::MAX`.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u128 {
+        u128::from(self.end.abs_diff(self.start)) + 1
+    }
+    pub fn contains(&self, x: i64) -> bool {
+        self.start <= x && x <= self.end
+    }
+    /// Whether all of `other` lies within this interval.
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+    /// Whether the two intervals have any integer in common.
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+    /// The two intervals as one, if they overlap or are next to each other. Otherwise there's a
+    /// gap between them that no single interval can leave out.
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        let touching =
+            self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1);
+        touching.then(|| self.hull(other))
+    }
+    /// The smallest interval containing both.
+    pub fn hull(&self, other: &Interval) -> Interval {
+        Interval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// A set of integers, stored as the fewest intervals that cover it: sorted, with no two
+/// overlapping or adjacent.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+    /// Every integer that lies in at least `k` of `intervals`, counting repeats. `k` = 1 gives
+    /// their union; `k` = 0 is treated as 1, since an integer in none of them has no bounds.
+    pub fn covered_at_least(
+        intervals: impl IntoIterator<Item = Interval>,
+        k: usize,
+    ) -> IntervalSet {
+        // Sweep over the endpoints: +1 where an interval starts, -1 just after it ends. Ends sort
+        // before starts at the same point, so the depth can dip for a moment there, but `insert`
+        // joins the two pieces back up. Just after `i64::MAX` needs a wider key.
+        let mut events = Vec::new();
+        for i in intervals {
+            events.push((i128::from(i.start), 1));
+            events.push((i128::from(i.end) + 1, -1));
+        }
+        events.sort_unstable();
+        let k = k.max(1) as i64;
+        let mut set = IntervalSet::new();
+        let mut depth = 0;
+        let mut since = None;
+        for (x, delta) in events {
+            depth += delta;
+            match since {
+                None if depth >= k => since = Some(x),
+                Some(start) if depth < k => {
+                    since = None;
+                    // Depth only rises at a start, so `start` and `x - 1` are both in range.
+                    if let Some(i) = Interval::new(start as i64, (x - 1) as i64) {
+                        set.insert(i);
+                    }
+                }
+                _ => {}
+            }
+        }
+        set
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+    /// How many integers the set holds.
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+    /// The smallest interval containing the whole set.
+    pub fn span(&self) -> Option<Interval> {
+        Some(self.intervals.first()?.hull(self.intervals.last()?))
+    }
+    pub fn contains(&self, x: i64) -> bool {
+        let i = self.intervals.partition_point(|i| i.end < x);
+        self.intervals.get(i).is_some_and(|i| i.contains(x))
+    }
+    pub fn insert(&mut self, interval: Interval) {
+        // Everything from `first` up to `last` touches the new interval, and merges into it.
+        let first = self
+            .intervals
+            .partition_point(|i| i.end.saturating_add(1) < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|i| i.start <= interval.end.saturating_add(1));
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |a, b| a.hull(b));
+        self.intervals.splice(first..last, [merged]);
+    }
+    /// The parts of `within` that aren't in the set.
+    pub fn gaps(&self, within: Interval) -> IntervalSet {
+        let mut gaps = IntervalSet::new();
+        // Where the next gap could start; `None` once the set runs up to `i64::MAX`.
+        let mut next = Some(within.start);
+        for i in &self.intervals {
+            let Some(from) = next else { break };
+            if i.end < from {
+                continue;
+            }
+            if i.start > within.end {
+                break;
+            }
+            if i.start > from {
+                gaps.intervals.push(Interval {
+                    start: from,
+                    end: i.start - 1,
+                });
+            }
+            next = i.end.checked_add(1);
+        }
+        if let Some(from) = next {
+            gaps.intervals.extend(Interval::new(from, within.end));
+        }
+        gaps
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        for i in intervals {
+            set.insert(i);
+        }
+        set
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (n, i) in self.intervals.iter().enumerate() {
+            if n > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}", i)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+pub mod grid;
+pub mod inputs;
+pub mod interval;
+pub mod parse;
+pub mod search;
+pub mod testing;
+
+pub use parse::ParseError;
+
+/// The answer to one part of a puzzle. Most are numbers, but a few days produce text, e.g. the
+/// crate tops on day 5 or the CRT picture on day 10.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// For puzzles without a second part.
+    None,
+}
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => f.write_str(s),
+            Answer::None => Ok(()),
+        }
+    }
+}
+impl From<i32> for Answer {
+    fn from(n: i32) -> Answer {
+        Answer::Number(n.into())
+    }
+}
+impl From<i64> for Answer {
+    fn from(n: i64) -> Answer {
+        Answer::Number(n)
+    }
+}
+impl From<u32> for Answer {
+    fn from(n: u32) -> Answer {
+        Answer::Number(n.into())
+    }
+}
+impl From<u64> for Answer {
+    fn from(n: u64) -> Answer {
+        u128::from(n).into()
+    }
+}
+/// Anything too big for `Number` is kept as text, so it still prints in full.
+impl From<u128> for Answer {
+    fn from(n: u128) -> Answer {
+        match i64::try_from(n) {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(n.to_string()),
+        }
+    }
+}
+impl From<usize> for Answer {
+    fn from(n: usize) -> Answer {
+        (n as u128).into()
+    }
+}
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+/// One day's puzzle. Each `aocNN` crate exports a unit struct implementing this, which delegates
+/// to the crate's `parse`, `solve_part1` and `solve_part2`.
+pub trait Solution {
+    const DAY: u32;
+    type Input;
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+    /// Part 1 for one of the puzzle's examples. Only puzzles that change a constant for their
+    /// examples (a row number, a grid size) need to override it.
+    fn example_part1(input: &Self::Input) -> Answer {
+        Self::part1(input)
+    }
+    /// Part 2 for one of the puzzle's examples; see `example_part1`.
+    fn example_part2(input: &Self::Input) -> Answer {
+        Self::part2(input)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Answer,
+    pub part2: Answer,
+}
+
+/// How long each stage of a `Day::run_timed` took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// Object-safe view of a `Solution`, so the runner can keep every day in one list.
+pub trait Day {
+    fn day(&self) -> u32;
+    fn run(&self, input: &str) -> Result<Answers, ParseError> {
+        self.run_timed(input).map(|(answers, _)| answers)
+    }
+    fn run_timed(&self, input: &str) -> Result<(Answers, Timings), ParseError>;
+    /// Like `run`, but for one of the puzzle's examples.
+    fn run_example(&self, input: &str) -> Result<Answers, ParseError> {
+        self.run_example_timed(input).map(|(answers, _)| answers)
+    }
+    fn run_example_timed(&self, input: &str) -> Result<(Answers, Timings), ParseError>;
+}
+impl<S: Solution> Day for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+    fn run_timed(&self, input: &str) -> Result<(Answers, Timings), ParseError> {
+        run_timed::<S>(input)
+    }
+    fn run_example_timed(&self, input: &str) -> Result<(Answers, Timings), ParseError> {
+        run_example_timed::<S>(input)
+    }
+}
+
+/// Parses `input` and solves both parts, timing each stage.
+pub fn run_timed<S: Solution>(input: &str) -> Result<(Answers, Timings), ParseError> {
+    time_parts::<S>(input, S::part1, S::part2)
+}
+
+/// Like `run_timed`, but solves `input` as one of the puzzle's examples.
+pub fn run_example_timed<S: Solution>(input: &str) -> Result<(Answers, Timings), ParseError> {
+    time_parts::<S>(input, S::example_part1, S::example_part2)
+}
+
+fn time_parts<S: Solution>(
+    input: &str,
+    part1: fn(&S::Input) -> Answer,
+    part2: fn(&S::Input) -> Answer,
+) -> Result<(Answers, Timings), ParseError> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parsed = Instant::now();
+    let part1 = part1(&input);
+    let solved1 = Instant::now();
+    let part2 = part2(&input);
+    let solved2 = Instant::now();
+    let timings = Timings {
+        parse: parsed - start,
+        part1: solved1 - parsed,
+        part2: solved2 - solved1,
+    };
+    Ok((Answers { part1, part2 }, timings))
+}
+
+/// How the binaries print their answers. Whatever the format, only the answers go to stdout;
+/// errors and debug output go to stderr.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// `Part 1: ...` and `Part 2: ...` lines, for people.
+    #[default]
+    Text,
+    /// One JSON object per day on a single line, for scripts and dashboards.
+    Json,
+}
+impl Format {
+    pub fn parse(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Invalid format: {} (expected text or json)", s)),
+        }
+    }
+}
+
+fn answer_to_json(answer: &Answer) -> serde_json::Value {
+    match answer {
+        Answer::Number(n) => (*n).into(),
+        Answer::Text(s) => s.as_str().into(),
+        Answer::None => serde_json::Value::Null,
+    }
+}
+
+/// Renders the answers for one day. The text format leaves out a missing second part; the JSON
+/// format reports it as `null`.
+pub fn report(day: u32, answers: &Answers, timings: &Timings, format: Format) -> String {
+    match format {
+        Format::Text => {
+            let mut s = format!("Part 1: {}", answers.part1);
+            if answers.part2 != Answer::None {
+                s += &format!("\nPart 2: {}", answers.part2);
+            }
+            s
+        }
+        // Written out by hand to keep the keys in this order; serde_json would sort them.
+        Format::Json => format!(
+            "{{\"day\":{},\"part1\":{},\"part2\":{},\"elapsed_ms\":{:.3}}}",
+            day,
+            answer_to_json(&answers.part1),
+            answer_to_json(&answers.part2),
+            timings.total().as_secs_f64() * 1000.0
+        ),
+    }
+}
+
+/// Removes any `-v`, `-vv` (and so on) or `--verbose` flags from `args`, returning how many
+/// levels of verbosity they asked for.
+pub fn take_verbosity(args: &mut Vec<String>) -> u8 {
+    let mut verbosity = 0;
+    args.retain(|arg| {
+        if arg == "--verbose" {
+            verbosity += 1;
+            false
+        } else if arg.len() > 1 && arg.starts_with('-') && arg[1..].bytes().all(|b| b == b'v') {
+            verbosity += arg.len() as u8 - 1;
+            false
+        } else {
+            true
+        }
+    });
+    verbosity
+}
+
+/// Sends the solvers' `log` output to stderr. By default only warnings are shown; `-v` turns on
+/// debug output and `-vv` trace output. `RUST_LOG` is applied on top using the usual
+/// `env_logger` syntax, so e.g. `RUST_LOG=aoc09=trace` traces day 9 alone.
+pub fn init_logging(verbosity: u8) {
+    let level = match verbosity {
+        0 => log::LevelFilter::Warn,
+        1 => log::LevelFilter::Debug,
+        _ => log::LevelFilter::Trace,
+    };
+    let mut builder = env_logger::Builder::new();
+    builder.filter_level(level).format_timestamp(None);
+    if let Ok(filters) = std::env::var("RUST_LOG") {
+        builder.parse_filters(&filters);
+    }
+    // Only fails if a logger is already installed, which is fine to keep.
+    let _ = builder.try_init();
+}
+
+/// Reads all of stdin, for the per-day binaries.
+pub fn read_stdin() -> String {
+    std::io::read_to_string(std::io::stdin()).unwrap()
+}
+
+/// The whole of a per-day binary: solve the input on stdin and print both parts. The only
+/// options are `--format <text|json>` and the verbosity flags.
+pub fn main<S: Solution>() -> ExitCode {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    init_logging(take_verbosity(&mut args));
+    let format = match args.as_slice() {
+        [] => Ok(Format::Text),
+        [flag, format] if flag == "--format" => Format::parse(format),
+        _ => Err("Usage: [-v|-vv] [--format <text|json>] < INPUT".to_string()),
+    };
+    let format = match format {
+        Ok(format) => format,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    match run_timed::<S>(&read_stdin()) {
+        Ok((answers, timings)) => {
+            println!("{}", report(S::DAY, &answers, &timings, format));
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e.pretty("<stdin>"));
+            ExitCode::FAILURE
+        }
+    }
+}
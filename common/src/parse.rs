@@ -0,0 +1,251 @@
+//! Position-aware helpers for the day parsers. Every token remembers the line it came from, so a
+//! parser can turn any unexpected token into a `ParseError` pointing at the right line and column.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Where a parse error happened. Line and column are 1-based, and the column counts characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    /// The full text of the offending line, so diagnostics can show it without the input.
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub location: Option<Location>,
+    /// What the parser was looking for, e.g. "integer" or "`->`".
+    pub expected: String,
+    /// What it got instead, if there was anything to point at.
+    pub found: Option<String>,
+}
+impl ParseError {
+    /// An error about the input as a whole, with no particular position.
+    pub fn new(expected: impl Into<String>) -> ParseError {
+        ParseError {
+            location: None,
+            expected: expected.into(),
+            found: None,
+        }
+    }
+    /// Renders the error like a compiler diagnostic, quoting the offending line with a caret
+    /// under the column. `source` names the input, e.g. a file path.
+    pub fn pretty(&self, source: &str) -> String {
+        let mut s = format!("error: {}\n", self.message());
+        match &self.location {
+            Some(loc) => {
+                let gutter = " ".repeat(loc.line.to_string().len());
+                let width = match &self.found {
+                    Some(found) if !found.is_empty() => found.chars().count(),
+                    _ => 1,
+                };
+                s += &format!("{}--> {}:{}:{}\n", gutter, source, loc.line, loc.column);
+                s += &format!("{} |\n", gutter);
+                s += &format!("{} | {}\n", loc.line, loc.text);
+                s += &format!(
+                    "{} | {}{}",
+                    gutter,
+                    " ".repeat(loc.column - 1),
+                    "^".repeat(width)
+                );
+            }
+            None => s += &format!(" --> {}", source),
+        }
+        s
+    }
+    fn message(&self) -> String {
+        match &self.found {
+            Some(found) if found.is_empty() => {
+                format!("expected {}, found end of line", self.expected)
+            }
+            Some(found) => format!("expected {}, found `{}`", self.expected, found),
+            None => format!("expected {}", self.expected),
+        }
+    }
+}
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(loc) = &self.location {
+            write!(f, "line {}, column {}: ", loc.line, loc.column)?;
+        }
+        f.write_str(&self.message())
+    }
+}
+impl std::error::Error for ParseError {}
+
+/// Iterates over the lines of `input`, numbering them from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+impl<'a> Line<'a> {
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+    /// The whole line as a single token.
+    pub fn token(&self) -> Token<'a> {
+        Token {
+            line: *self,
+            text: self.text,
+        }
+    }
+    /// Whitespace-separated tokens.
+    pub fn tokens(&self) -> Tokens<'a> {
+        Tokens::new(*self, self.text.split_ascii_whitespace())
+    }
+    /// Tokens separated by any of the characters in `separators`.
+    pub fn split(&self, separators: &[char]) -> Tokens<'a> {
+        Tokens::new(*self, self.text.split(separators))
+    }
+    /// Characters along with their 1-based column.
+    pub fn chars(&self) -> impl Iterator<Item = (usize, char)> + 'a {
+        self.text.chars().enumerate().map(|(i, c)| (i + 1, c))
+    }
+    /// An error at `column`, pointing at the character there.
+    pub fn error_at(&self, column: usize, expected: impl Into<String>) -> ParseError {
+        let found = self.text.chars().nth(column - 1).map(String::from);
+        ParseError {
+            location: Some(self.location(column)),
+            expected: expected.into(),
+            found: Some(found.unwrap_or_default()),
+        }
+    }
+    /// An error at the end of the line, for when it stops too early.
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            location: Some(self.location(self.text.chars().count() + 1)),
+            expected: expected.into(),
+            found: Some(String::new()),
+        }
+    }
+    fn location(&self, column: usize) -> Location {
+        Location {
+            line: self.number,
+            column,
+            text: self.text.to_string(),
+        }
+    }
+}
+
+/// A piece of a line. Narrowing it (`strip_prefix`, `split_once`, ...) keeps track of its column.
+#[derive(Debug, Clone, Copy)]
+pub struct Token<'a> {
+    line: Line<'a>,
+    text: &'a str,
+}
+impl<'a> Token<'a> {
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+    pub fn line(&self) -> Line<'a> {
+        self.line
+    }
+    pub fn column(&self) -> usize {
+        let offset = self.text.as_ptr() as usize - self.line.text.as_ptr() as usize;
+        self.line.text[..offset].chars().count() + 1
+    }
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            location: Some(self.line.location(self.column())),
+            expected: expected.into(),
+            found: Some(self.text.to_string()),
+        }
+    }
+    pub fn parse<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        self.text.parse::<T>().map_err(|_| self.error(expected))
+    }
+    pub fn expect(&self, literal: &str) -> Result<(), ParseError> {
+        if self.text == literal {
+            Ok(())
+        } else {
+            Err(self.error(format!("`{}`", literal)))
+        }
+    }
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Token<'a>, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.sub(rest)),
+            None => Err(self.error(format!("`{}`", prefix))),
+        }
+    }
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Token<'a>, ParseError> {
+        match self.text.strip_suffix(suffix) {
+            Some(rest) => Ok(self.sub(rest)),
+            None => Err(self.error(format!("`{}` at the end", suffix))),
+        }
+    }
+    pub fn split_once(&self, separator: &str) -> Result<(Token<'a>, Token<'a>), ParseError> {
+        match self.text.split_once(separator) {
+            Some((a, b)) => Ok((self.sub(a), self.sub(b))),
+            None => Err(self.error(format!("`{}`", separator))),
+        }
+    }
+    /// Tokens separated by any of the characters in `separators`.
+    pub fn split(&self, separators: &[char]) -> Tokens<'a> {
+        Tokens::new(self.line, self.text.split(separators))
+    }
+    /// Characters along with their 1-based column.
+    pub fn chars(&self) -> impl Iterator<Item = (usize, char)> + 'a {
+        let column = self.column();
+        self.text
+            .chars()
+            .enumerate()
+            .map(move |(i, c)| (column + i, c))
+    }
+    /// `text` must be a slice of this token.
+    fn sub(&self, text: &'a str) -> Token<'a> {
+        Token {
+            line: self.line,
+            text,
+        }
+    }
+}
+
+/// The tokens of a line, with helpers that turn running out of tokens into an error.
+pub struct Tokens<'a> {
+    line: Line<'a>,
+    tokens: std::vec::IntoIter<&'a str>,
+}
+impl<'a> Tokens<'a> {
+    fn new(line: Line<'a>, tokens: impl Iterator<Item = &'a str>) -> Tokens<'a> {
+        Tokens {
+            line,
+            tokens: tokens.collect::<Vec<_>>().into_iter(),
+        }
+    }
+    /// The next token, or an error saying what was `expected` in its place.
+    pub fn next_token(&mut self, expected: &str) -> Result<Token<'a>, ParseError> {
+        self.next().ok_or_else(|| self.line.error_at_end(expected))
+    }
+    pub fn parse_next<T: FromStr>(&mut self, expected: &str) -> Result<T, ParseError> {
+        self.next_token(expected)?.parse(expected)
+    }
+    pub fn expect(&mut self, literal: &str) -> Result<(), ParseError> {
+        self.next_token(&format!("`{}`", literal))?.expect(literal)
+    }
+    /// Errors if there are tokens left over.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        match self.next() {
+            Some(token) => Err(token.error("end of line")),
+            None => Ok(()),
+        }
+    }
+}
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+    fn next(&mut self) -> Option<Token<'a>> {
+        self.tokens.next().map(|text| Token {
+            line: self.line,
+            text,
+        })
+    }
+}
@@ -0,0 +1,233 @@
+//! Searches over implicit graphs, where a puzzle describes its states and how to move between
+//! them and the search does the bookkeeping.
+//!
+//! Shortest paths (`bfs`, `dijkstra` and `a_star`) need a `Successors` implementation, plus a
+//! `Heuristic` for A*. Maximisation problems too big to enumerate go to `branch_and_bound`, which
+//! needs a `Bounded` implementation. Every search returns the path it found as well as its value.
+
+use log::debug;
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// The best value a search found and the states that lead to it, from a start state to the
+/// state where it ended, inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S, V> {
+    pub value: V,
+    pub path: Vec<S>,
+}
+
+/// A graph for the shortest-path searches.
+pub trait Successors {
+    type State: Clone + Eq + Hash;
+    /// The states one step away from `state`, each with the cost of that step.
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, usize)>;
+}
+
+/// A guess at the remaining cost, to point A* at the goal.
+pub trait Heuristic: Successors {
+    /// A lower bound on the cost from `state` to the nearest goal. If it ever overestimates, A*
+    /// can return a path that isn't the cheapest.
+    fn estimate(&self, state: &Self::State) -> usize;
+}
+
+/// A maximisation problem for `branch_and_bound`.
+pub trait Bounded {
+    type State: Clone;
+    type Value: Copy + Ord;
+    /// The states that `state` can turn into. They're explored in this order, so put the most
+    /// promising first: finding a good value early lets more of the rest be pruned.
+    fn successors(&self, state: &Self::State) -> Vec<Self::State>;
+    /// The value of `state` as it stands, i.e. if the search stopped here.
+    fn value(&self, state: &Self::State) -> Self::Value;
+    /// An upper bound on the value of `state` and everything reachable from it.
+    fn bound(&self, state: &Self::State) -> Self::Value;
+}
+
+/// The fewest steps from any of `starts` to a state matching `is_goal`, for graphs where every
+/// step costs the same. Step costs are ignored; the value is the number of steps.
+pub fn bfs<G: Successors>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::State>,
+    mut is_goal: impl FnMut(&G::State) -> bool,
+) -> Option<Found<G::State, usize>> {
+    let mut parents: HashMap<G::State, Option<G::State>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(e) = parents.entry(start.clone()) {
+            e.insert(None);
+            queue.push_back((start, 0));
+        }
+    }
+    while let Some((state, steps)) = queue.pop_front() {
+        if is_goal(&state) {
+            debug!("bfs: explored {} states", parents.len());
+            let mut path = vec![state];
+            while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some(Found { value: steps, path });
+        }
+        for (next, _) in graph.successors(&state) {
+            if let Entry::Vacant(e) = parents.entry(next.clone()) {
+                e.insert(Some(state.clone()));
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    debug!(
+        "bfs: explored {} states without reaching a goal",
+        parents.len()
+    );
+    None
+}
+
+/// The cheapest path from any of `starts` to a state matching `is_goal`.
+pub fn dijkstra<G: Successors>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::State>,
+    is_goal: impl FnMut(&G::State) -> bool,
+) -> Option<Found<G::State, usize>> {
+    best_first(graph, starts, is_goal, |_| 0)
+}
+
+/// Like `dijkstra`, but exploring the states that `Heuristic::estimate` says are closest to a
+/// goal first.
+pub fn a_star<G: Heuristic>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::State>,
+    is_goal: impl FnMut(&G::State) -> bool,
+) -> Option<Found<G::State, usize>> {
+    best_first(graph, starts, is_goal, |state| graph.estimate(state))
+}
+
+/// A state `best_first` has seen, with the cheapest known cost to reach it.
+struct Node<S> {
+    state: S,
+    cost: usize,
+    parent: Option<usize>,
+}
+
+fn best_first<G: Successors>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::State>,
+    mut is_goal: impl FnMut(&G::State) -> bool,
+    estimate: impl Fn(&G::State) -> usize,
+) -> Option<Found<G::State, usize>> {
+    // States live in `nodes` and are referred to by index, so they don't need to be `Ord` to
+    // go in the heap.
+    let mut nodes = Vec::new();
+    let mut index = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if let Entry::Vacant(e) = index.entry(start.clone()) {
+            e.insert(nodes.len());
+            heap.push(Reverse((estimate(&start), 0, nodes.len())));
+            nodes.push(Node {
+                state: start,
+                cost: 0,
+                parent: None,
+            });
+        }
+    }
+    let mut explored = 0;
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        if cost > nodes[i].cost {
+            // Already reached more cheaply since this was queued.
+            continue;
+        }
+        explored += 1;
+        if is_goal(&nodes[i].state) {
+            debug!(
+                "best-first: explored {} of {} states",
+                explored,
+                nodes.len()
+            );
+            let mut path = Vec::new();
+            let mut at = Some(i);
+            while let Some(j) = at {
+                path.push(nodes[j].state.clone());
+                at = nodes[j].parent;
+            }
+            path.reverse();
+            return Some(Found { value: cost, path });
+        }
+        for (next, step) in graph.successors(&nodes[i].state) {
+            let next_cost = cost + step;
+            let j = match index.entry(next) {
+                Entry::Occupied(e) => {
+                    let j = *e.get();
+                    if nodes[j].cost <= next_cost {
+                        continue;
+                    }
+                    nodes[j].cost = next_cost;
+                    nodes[j].parent = Some(i);
+                    j
+                }
+                Entry::Vacant(e) => {
+                    let j = nodes.len();
+                    nodes.push(Node {
+                        state: e.key().clone(),
+                        cost: next_cost,
+                        parent: Some(i),
+                    });
+                    e.insert(j);
+                    j
+                }
+            };
+            heap.push(Reverse((
+                next_cost + estimate(&nodes[j].state),
+                next_cost,
+                j,
+            )));
+        }
+    }
+    debug!(
+        "best-first: explored {} states without reaching a goal",
+        explored
+    );
+    None
+}
+
+/// The highest-valued state reachable from `start`, found depth first. Anything whose
+/// `Bounded::bound` can't beat the best value so far is skipped, along with everything reachable
+/// from it. Each state is explored once per path that reaches it, so this suits trees rather than
+/// graphs that often reconverge.
+pub fn branch_and_bound<P: Bounded>(problem: &P, start: P::State) -> Found<P::State, P::Value> {
+    let mut best = Found {
+        value: problem.value(&start),
+        path: vec![start.clone()],
+    };
+    let mut path = vec![start];
+    let mut explored = 0;
+    explore(problem, &mut path, &mut best, &mut explored);
+    debug!("branch and bound: explored {} states", explored);
+    best
+}
+
+fn explore<P: Bounded>(
+    problem: &P,
+    path: &mut Vec<P::State>,
+    best: &mut Found<P::State, P::Value>,
+    explored: &mut u64,
+) {
+    *explored += 1;
+    let state = path.last().unwrap();
+    let value = problem.value(state);
+    if value > best.value {
+        best.value = value;
+        best.path = path.clone();
+    }
+    for next in problem.successors(state) {
+        // The best value can go up while exploring earlier siblings, so check each one as it
+        // comes up rather than all at once.
+        if problem.bound(&next) > best.value {
+            path.push(next);
+            explore(problem, path, best, explored);
+            path.pop();
+        }
+    }
+}
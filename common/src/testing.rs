@@ -0,0 +1,79 @@
+//! Helpers for the per-day regression tests in `aocNN/tests/`.
+//!
+//! The published examples live in `examples/` at the workspace root and are checked into git.
+//! Real puzzle inputs are personal, so they live in the git-ignored `inputs/` directory instead,
+//! or wherever `AOC_INPUT_DIR` points: `dayNN.txt` holds the input, and `dayNN.part1` /
+//! `dayNN.part2` hold the accepted answers. Any of these files can be missing, in which case that
+//! check is skipped.
+
+use crate::inputs::{input_dir, input_path};
+use crate::Solution;
+use std::fs;
+
+/// Parses `input`, panicking with the pretty-printed error if it doesn't parse.
+pub fn parse<S: Solution>(input: &str) -> S::Input {
+    match S::parse(input) {
+        Ok(input) => input,
+        Err(e) => panic!("\n{}", e.pretty(&format!("day {} input", S::DAY))),
+    }
+}
+
+/// Solves the real input for `S` from `inputs/`, if there is one, and checks each part against
+/// its recorded answer, if there is one.
+pub fn check_real_input<S: Solution>() {
+    let dir = input_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs"));
+    let path = input_path(&dir, S::DAY);
+    let input = match fs::read_to_string(&path) {
+        Ok(input) => input,
+        Err(_) => {
+            eprintln!("skipping: no real input at {}", path.display());
+            return;
+        }
+    };
+    let input = parse::<S>(&input);
+    let answers = [S::part1(&input), S::part2(&input)];
+    for (part, answer) in (1..).zip(answers) {
+        let answer_path = dir.join(format!("day{:02}.part{}", S::DAY, part));
+        let expected = match fs::read_to_string(&answer_path) {
+            Ok(expected) => expected,
+            Err(_) => {
+                eprintln!(
+                    "skipping part {}: no answer at {}",
+                    part,
+                    answer_path.display()
+                );
+                continue;
+            }
+        };
+        // Answer files are hand-written, so ignore surrounding whitespace on both sides.
+        assert_eq!(
+            answer.to_string().trim(),
+            expected.trim(),
+            "day {} part {}",
+            S::DAY,
+            part
+        );
+    }
+}
+
+/// A seeded xorshift generator, for tests and benchmarks that need a lot of varied input but the
+/// same input on every run.
+pub struct Rng(u64);
+
+impl Rng {
+    /// `seed` can be anything but 0, which xorshift never leaves.
+    pub fn new(seed: u64) -> Rng {
+        assert_ne!(seed, 0, "xorshift needs a nonzero seed");
+        Rng(seed)
+    }
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    /// A number from 0 up to but not including `n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
@@ -0,0 +1,69 @@
+use common::grid::{Grid, Point, SparseGrid};
+
+const MAP: &str = "#..\n.#.\n..#\n";
+
+fn parse(input: &str) -> Result<Grid<char>, common::ParseError> {
+    Grid::parse(input, "`#` or `.`", |c| matches!(c, '#' | '.').then_some(c))
+}
+
+#[test]
+fn parse_and_display_round_trip() {
+    let grid = parse(MAP).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 3));
+    assert_eq!(grid[Point::new(1, 1)], '#');
+    assert_eq!(grid.to_string(), MAP);
+}
+
+#[test]
+fn parse_reports_bad_cells_and_ragged_rows() {
+    let e = parse("#..\n.x.\n").unwrap_err();
+    let location = e.location.unwrap();
+    assert_eq!((location.line, location.column), (2, 2));
+    assert_eq!(e.expected, "`#` or `.`");
+
+    let e = parse("#..\n.#\n").unwrap_err();
+    assert_eq!(e.location.unwrap().column, 3);
+    let e = parse("#..\n.#..\n").unwrap_err();
+    assert_eq!(e.location.unwrap().column, 4);
+}
+
+#[test]
+fn padded_rows() {
+    let grid = Grid::from_lines_padded(common::parse::lines("#\n.#."), ' ', "tile", Some).unwrap();
+    assert_eq!(grid.to_string(), "#  \n.#.\n");
+}
+
+#[test]
+fn offset_origin() {
+    let mut grid = Grid::with_origin(Point::new(-2, 10), 3, 2, 0);
+    assert!(grid.contains(Point::new(-2, 10)));
+    assert!(grid.contains(Point::new(0, 11)));
+    assert!(!grid.contains(Point::new(1, 11)));
+    assert!(!grid.contains(Point::new(0, 9)));
+    assert_eq!(grid.end(), Point::new(0, 11));
+    grid[Point::new(0, 11)] = 5;
+    assert_eq!(grid.position(|&n| n == 5), Some(Point::new(0, 11)));
+    assert_eq!(grid.get(Point::new(0, 12)), None);
+}
+
+#[test]
+fn neighbors_stop_at_the_edges() {
+    let grid = parse(MAP).unwrap();
+    let corner = grid.neighbors4(Point::new(0, 0)).collect::<Vec<_>>();
+    assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+    assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 3);
+    assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+    assert_eq!(Point::new(0, 0).neighbors4().count(), 4);
+}
+
+#[test]
+fn sparse_grid_bounds() {
+    let mut grid = SparseGrid::new();
+    assert_eq!(grid.bounds(), None);
+    grid.insert(Point::new(-1, 2), '#');
+    grid.insert(Point::new(1, 0), '#');
+    assert_eq!(grid.bounds(), Some((Point::new(-1, 0), Point::new(1, 2))));
+    assert_eq!(grid.to_string(), "..#\n...\n#..\n");
+    grid.remove(Point::new(-1, 2));
+    assert_eq!(grid.to_string(), "#\n");
+}
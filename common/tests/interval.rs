@@ -0,0 +1,90 @@
+use common::interval::{Interval, IntervalSet};
+
+fn iv(start: i64, end: i64) -> Interval {
+    Interval::new(start, end).unwrap()
+}
+
+#[test]
+fn interval_algebra() {
+    assert_eq!(Interval::new(3, 2), None);
+    assert_eq!(iv(2, 4).len(), 3);
+    assert_eq!(Interval::point(7).len(), 1);
+    assert!(iv(2, 8).contains_interval(&iv(3, 7)));
+    assert!(!iv(3, 7).contains_interval(&iv(2, 8)));
+    assert!(iv(5, 7).overlaps(&iv(7, 9)));
+    assert!(!iv(2, 4).overlaps(&iv(6, 8)));
+    assert_eq!(iv(5, 7).intersection(&iv(7, 9)), Some(iv(7, 7)));
+    assert_eq!(iv(2, 4).intersection(&iv(6, 8)), None);
+    assert_eq!(iv(2, 4).union(&iv(5, 8)), Some(iv(2, 8)));
+    assert_eq!(iv(2, 4).union(&iv(6, 8)), None);
+    assert_eq!(iv(2, 4).hull(&iv(6, 8)), iv(2, 8));
+    assert_eq!(iv(-3, 4).to_string(), "-3-4");
+}
+
+#[test]
+fn sets_merge_overlapping_and_adjacent_intervals() {
+    let mut set = [iv(10, 12), iv(1, 3), iv(5, 6)]
+        .into_iter()
+        .collect::<IntervalSet>();
+    assert_eq!(set.intervals(), [iv(1, 3), iv(5, 6), iv(10, 12)]);
+    assert_eq!(set.len(), 8);
+    set.insert(iv(4, 4));
+    assert_eq!(set.intervals(), [iv(1, 6), iv(10, 12)]);
+    set.insert(iv(0, 20));
+    assert_eq!(set.intervals(), [iv(0, 20)]);
+    assert!(set.contains(20));
+    assert!(!set.contains(21));
+    assert!(IntervalSet::new().is_empty());
+}
+
+#[test]
+fn gaps() {
+    let set = [iv(1, 3), iv(5, 6), iv(10, 12)]
+        .into_iter()
+        .collect::<IntervalSet>();
+    assert_eq!(set.span(), Some(iv(1, 12)));
+    assert_eq!(set.gaps(iv(1, 12)).to_string(), "4-4,7-9");
+    assert_eq!(set.gaps(iv(-2, 15)).to_string(), "-2-0,4-4,7-9,13-15");
+    assert_eq!(set.gaps(iv(7, 8)).intervals(), [iv(7, 8)]);
+    assert!(set.gaps(iv(2, 3)).is_empty());
+}
+
+#[test]
+fn coverage_depth() {
+    let intervals = [iv(1, 5), iv(3, 8), iv(4, 4), iv(6, 6), iv(9, 9)];
+    let at_least = |k| IntervalSet::covered_at_least(intervals, k).to_string();
+    assert_eq!(at_least(1), "1-9");
+    assert_eq!(at_least(2), "3-6");
+    assert_eq!(at_least(3), "4-4");
+    assert_eq!(at_least(4), "");
+}
+
+#[test]
+fn the_ends_of_i64() {
+    let all = iv(i64::MIN, i64::MAX);
+    assert_eq!(all.len(), u128::from(u64::MAX) + 1);
+    assert_eq!(IntervalSet::from_iter([all]).len(), all.len());
+    assert_eq!(iv(i64::MAX, i64::MAX).len(), 1);
+
+    let top = [iv(i64::MAX - 2, i64::MAX), iv(i64::MAX - 1, i64::MAX)];
+    let at_least = |k| IntervalSet::covered_at_least(top, k);
+    assert_eq!(at_least(1).intervals(), [top[0]]);
+    assert_eq!(at_least(2).intervals(), [top[1]]);
+    let bottom = [iv(i64::MIN, i64::MIN + 1), iv(i64::MIN, i64::MIN)];
+    assert_eq!(
+        IntervalSet::covered_at_least(bottom, 2).intervals(),
+        [bottom[1]]
+    );
+
+    let set = IntervalSet::from_iter([iv(i64::MIN, -1), iv(i64::MAX - 1, i64::MAX)]);
+    assert_eq!(set.gaps(all).intervals(), [iv(0, i64::MAX - 2)]);
+    assert!(set.gaps(iv(i64::MIN, i64::MIN)).is_empty());
+    assert!(set.gaps(iv(i64::MAX, i64::MAX)).is_empty());
+    assert_eq!(
+        IntervalSet::from_iter([iv(5, i64::MAX)])
+            .gaps(all)
+            .intervals(),
+        [iv(i64::MIN, 4)]
+    );
+    assert!(IntervalSet::from_iter([all]).gaps(all).is_empty());
+}
@@ -0,0 +1,133 @@
+use common::grid::{Grid, Point};
+use common::search::{a_star, bfs, branch_and_bound, dijkstra, Bounded, Heuristic, Successors};
+
+/// Open cells are `.`, walls are `#`, and stepping onto a digit costs that much.
+struct Maze(Grid<char>);
+impl Maze {
+    fn new(map: &str) -> Maze {
+        Maze(Grid::parse(map, "tile", Some).unwrap())
+    }
+}
+impl Successors for Maze {
+    type State = Point;
+    fn successors(&self, &p: &Point) -> Vec<(Point, usize)> {
+        self.0
+            .neighbors4(p)
+            .filter_map(|n| match self.0[n] {
+                '#' => None,
+                '.' => Some((n, 1)),
+                c => Some((n, c.to_digit(10).unwrap() as usize)),
+            })
+            .collect()
+    }
+}
+impl Heuristic for Maze {
+    fn estimate(&self, &p: &Point) -> usize {
+        p.manhattan(self.0.end()) as usize
+    }
+}
+
+const MAZE: &str = "\
+..9..
+.#.#.
+.....
+";
+
+#[test]
+fn bfs_counts_steps() {
+    let maze = Maze::new(MAZE);
+    let found = bfs(&maze, [Point::new(0, 0)], |&p| p == Point::new(4, 0)).unwrap();
+    assert_eq!(found.value, 4);
+    assert_eq!(found.path.len(), 5);
+    assert_eq!(found.path[0], Point::new(0, 0));
+    assert_eq!(found.path[4], Point::new(4, 0));
+}
+
+#[test]
+fn dijkstra_and_a_star_avoid_expensive_steps() {
+    let maze = Maze::new(MAZE);
+    let goal = |&p: &Point| p == Point::new(4, 0);
+    for found in [
+        dijkstra(&maze, [Point::new(0, 0)], goal).unwrap(),
+        a_star(&maze, [Point::new(0, 0)], goal).unwrap(),
+    ] {
+        assert_eq!(found.value, 8);
+        assert!(!found.path.contains(&Point::new(2, 0)));
+        for step in found.path.windows(2) {
+            assert_eq!(step[0].manhattan(step[1]), 1);
+        }
+    }
+}
+
+#[test]
+fn searches_from_several_starts() {
+    let maze = Maze::new(MAZE);
+    let found = bfs(&maze, [Point::new(0, 0), Point::new(4, 2)], |&p| {
+        p == Point::new(4, 0)
+    });
+    assert_eq!(
+        found.unwrap().path,
+        [Point::new(4, 2), Point::new(4, 1), Point::new(4, 0)]
+    );
+}
+
+#[test]
+fn unreachable_goal() {
+    let maze = Maze::new(".#.\n");
+    assert_eq!(bfs(&maze, [Point::new(0, 0)], |&p| p.x == 2), None);
+    assert_eq!(dijkstra(&maze, [Point::new(0, 0)], |&p| p.x == 2), None);
+}
+
+/// 0/1 knapsack: states are the items taken so far, as decided up to some index.
+struct Knapsack {
+    items: Vec<(i32, i32)>,
+    capacity: i32,
+}
+impl Bounded for Knapsack {
+    type State = Vec<bool>;
+    type Value = i32;
+    fn successors(&self, taken: &Vec<bool>) -> Vec<Vec<bool>> {
+        if taken.len() == self.items.len() {
+            return Vec::new();
+        }
+        let mut next = Vec::new();
+        for take in [true, false] {
+            let mut t = taken.clone();
+            t.push(take);
+            let weight: i32 = self
+                .items
+                .iter()
+                .zip(&t)
+                .filter(|(_, &t)| t)
+                .map(|(i, _)| i.0)
+                .sum();
+            if weight <= self.capacity {
+                next.push(t);
+            }
+        }
+        next
+    }
+    fn value(&self, taken: &Vec<bool>) -> i32 {
+        self.items
+            .iter()
+            .zip(taken)
+            .filter(|(_, &t)| t)
+            .map(|(i, _)| i.1)
+            .sum()
+    }
+    fn bound(&self, taken: &Vec<bool>) -> i32 {
+        self.value(taken) + self.items[taken.len()..].iter().map(|i| i.1).sum::<i32>()
+    }
+}
+
+#[test]
+fn branch_and_bound_finds_the_best() {
+    let knapsack = Knapsack {
+        items: vec![(5, 10), (4, 40), (6, 30), (3, 50)],
+        capacity: 10,
+    };
+    let best = branch_and_bound(&knapsack, Vec::new());
+    assert_eq!(best.value, 90);
+    assert_eq!(best.path.last().unwrap(), &[false, true, false, true]);
+    assert_eq!(best.path[0], Vec::<bool>::new());
+}